@@ -5,7 +5,7 @@ use indexmap::{IndexMap, IndexSet};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::hint::black_box;
 
-use collect_failable::{TryExtend, TryExtendSafe};
+use collect_failable::{TryExtend, TryExtendInPlace, TryExtendSafe};
 
 /// Generate (key, value) pairs for map benchmarks
 fn gen_pairs(size: usize) -> Vec<(usize, usize)> {
@@ -17,6 +17,11 @@ fn gen_scalars(size: usize) -> Vec<usize> {
     (0..size).collect()
 }
 
+/// Generate string values, whose keys are expensive to clone, for set benchmarks
+fn gen_strings(size: usize) -> Vec<String> {
+    (0..size).map(|i| format!("item-{i:08}")).collect()
+}
+
 /// Generic benchmark comparing extend, try_extend, and try_extend_safe
 ///
 /// # Type Parameters
@@ -61,6 +66,64 @@ where
     group.finish();
 }
 
+/// Benchmark comparing the strong guarantee strategies of try_extend_safe, which stages items in
+/// a second collection, and try_extend_in_place, which inserts directly and logs inserted keys.
+///
+/// The undo log clones every inserted key, even on success, which the String set measures.
+/// IndexMap splits inserted items off by index instead, so logs and clones nothing.
+///
+/// A collection of `size` items is extended with `size` new items, either successfully, or
+/// failing on a collision with the last item.
+fn bench_strong_comparison<C, V, F>(c: &mut Criterion, sizes: &[usize], generate_data: F)
+where
+    C: Default + Extend<V> + TryExtendSafe<Vec<V>> + TryExtendInPlace<Vec<V>>,
+    <C as TryExtendSafe<Vec<V>>>::Error: std::fmt::Debug,
+    <C as TryExtendInPlace<Vec<V>>>::Error: std::fmt::Debug,
+    V: Clone,
+    F: Fn(usize) -> Vec<V>,
+{
+    let mut group = c.benchmark_group(format!("{:?} strong", TypeName::<C>::SHORT));
+
+    for &size in sizes {
+        group.throughput(Throughput::Elements(size as u64));
+
+        let setup = |collide: bool| {
+            let mut data = generate_data(size * 2);
+            let mut extension = data.split_off(size);
+            if collide {
+                extension.push(data[0].clone());
+            }
+
+            let mut collection = C::default();
+            collection.extend(data);
+            (collection, extension)
+        };
+
+        macro_rules! bench_method {
+            ($method:ident, $case:literal, $collide:expr) => {
+                let name = format!("{}/{}", stringify!($method), $case);
+                group.bench_with_input(BenchmarkId::new(name, size), &size, |b, _| {
+                    b.iter_batched(
+                        || setup($collide),
+                        |(mut collection, extension)| {
+                            _ = black_box(collection.$method(black_box(extension)));
+                            black_box(collection)
+                        },
+                        BatchSize::LargeInput,
+                    );
+                });
+            };
+        }
+
+        bench_method!(try_extend_safe, "success", false);
+        bench_method!(try_extend_in_place, "success", false);
+        bench_method!(try_extend_safe, "collision", true);
+        bench_method!(try_extend_in_place, "collision", true);
+    }
+
+    group.finish();
+}
+
 const BENCH_SIZES: [usize; 4] = [100, 1000, 5000, 10000];
 
 macro_rules! define_bench {
@@ -83,6 +146,22 @@ define_bench!(bench_btreeset, BTreeSet<usize>, &BENCH_SIZES, gen_scalars);
 define_bench!(bench_indexset, IndexSet<usize>, &BENCH_SIZES, gen_scalars);
 define_bench!(bench_hashbrown_set, hashbrown::HashSet<usize>, &BENCH_SIZES, gen_scalars);
 
+macro_rules! define_strong_bench {
+    ($fn_name:ident, $collection:ty, $sizes:expr, $gen_fn:expr) => {
+        fn $fn_name(c: &mut Criterion) {
+            bench_strong_comparison::<$collection, _, _>(c, $sizes, $gen_fn);
+        }
+    };
+}
+
+// Strong guarantee strategy benchmarks
+define_strong_bench!(bench_hashmap_strong, HashMap<usize, usize>, &BENCH_SIZES, gen_pairs);
+define_strong_bench!(bench_btreemap_strong, BTreeMap<usize, usize>, &BENCH_SIZES, gen_pairs);
+define_strong_bench!(bench_hashbrown_strong, hashbrown::HashMap<usize, usize>, &BENCH_SIZES, gen_pairs);
+define_strong_bench!(bench_indexmap_strong, IndexMap<usize, usize>, &BENCH_SIZES, gen_pairs);
+define_strong_bench!(bench_hashset_strong, HashSet<usize>, &BENCH_SIZES, gen_scalars);
+define_strong_bench!(bench_string_hashset_strong, HashSet<String>, &BENCH_SIZES, gen_strings);
+
 // Vec-like benchmarks
 define_bench!(bench_arrayvec, ArrayVec<usize, 1000>, &[100, 500, 1000], gen_scalars);
 
@@ -104,4 +183,10 @@ criterion_group!(
     targets = bench_arrayvec
 );
 
-criterion_main!(try_extend_map, try_extend_set, try_extend_vec);
+criterion_group!(
+    name = try_extend_strong;
+    config = Criterion::default();
+    targets = bench_hashmap_strong, bench_btreemap_strong, bench_hashbrown_strong, bench_indexmap_strong, bench_hashset_strong, bench_string_hashset_strong
+);
+
+criterion_main!(try_extend_map, try_extend_set, try_extend_vec, try_extend_strong);
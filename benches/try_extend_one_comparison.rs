@@ -0,0 +1,138 @@
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use display_as_debug::wrap::TypeName;
+use indexmap::{IndexMap, IndexSet};
+use std::collections::{BTreeSet, HashSet};
+use std::hash::Hash;
+use std::hint::black_box;
+
+use collect_failable::TryExtendOne;
+
+/// Generate (key, value) pairs for map benchmarks
+fn gen_pairs(size: usize) -> Vec<(usize, usize)> {
+    (0..size).map(|i| (i, i * 2)).collect()
+}
+
+/// Generate scalar values for set benchmarks
+fn gen_scalars(size: usize) -> Vec<usize> {
+    (0..size).collect()
+}
+
+/// A collection that can be extended with a `contains` check followed by an `insert`, the
+/// double lookup strategy that `try_extend_one` replaces.
+trait ContainsInsert<T> {
+    fn contains_insert(&mut self, item: T) -> Result<(), T>;
+}
+
+macro_rules! impl_contains_insert_for_set {
+    ($type:ident where [$($bounds:tt)*]) => {
+        impl<T: $($bounds)*> ContainsInsert<T> for $type<T> {
+            fn contains_insert(&mut self, item: T) -> Result<(), T> {
+                match self.contains(&item) {
+                    true => Err(item),
+                    false => {
+                        self.insert(item);
+                        Ok(())
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_contains_insert_for_set!(HashSet where [Eq + Hash]);
+impl_contains_insert_for_set!(BTreeSet where [Ord]);
+impl_contains_insert_for_set!(IndexSet where [Eq + Hash]);
+
+impl<K: Eq + Hash, V> ContainsInsert<(K, V)> for IndexMap<K, V> {
+    fn contains_insert(&mut self, (key, value): (K, V)) -> Result<(), (K, V)> {
+        match self.contains_key(&key) {
+            true => Err((key, value)),
+            false => {
+                self.insert(key, value);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Benchmark comparing a `contains` check followed by an `insert` against `try_extend_one`,
+/// for a collection of `size` items extended one at a time with either `size` new items, or
+/// the same `size` items again, so every item collides.
+///
+/// Sets implement `try_extend_one` via `replace`, so a colliding item costs two lookups, where
+/// `contains_insert` costs one. The `colliding` cases measure that cost.
+fn bench_extend_one_comparison<C, V, F>(c: &mut Criterion, sizes: &[usize], generate_data: F)
+where
+    C: Default + Extend<V> + ContainsInsert<V> + TryExtendOne<Item = V>,
+    V: Clone,
+    F: Fn(usize) -> Vec<V>,
+{
+    let mut group = c.benchmark_group(format!("{:?}", TypeName::<C>::SHORT));
+
+    for &size in sizes {
+        group.throughput(Throughput::Elements(size as u64));
+
+        let distinct = || {
+            let mut data = generate_data(size * 2);
+            let extension = data.split_off(size);
+
+            let mut collection = C::default();
+            collection.extend(data);
+            (collection, extension)
+        };
+
+        let colliding = || {
+            let data = generate_data(size);
+
+            let mut collection = C::default();
+            collection.extend(data.clone());
+            (collection, data)
+        };
+
+        macro_rules! bench_method {
+            ($method:ident, $setup:ident) => {
+                let id = BenchmarkId::new(concat!(stringify!($method), "/", stringify!($setup)), size);
+                group.bench_with_input(id, &size, |b, _| {
+                    b.iter_batched(
+                        $setup,
+                        |(mut collection, extension)| {
+                            extension.into_iter().for_each(|item| _ = black_box(collection.$method(black_box(item))));
+                            black_box(collection)
+                        },
+                        BatchSize::LargeInput,
+                    );
+                });
+            };
+        }
+
+        bench_method!(contains_insert, distinct);
+        bench_method!(try_extend_one, distinct);
+        bench_method!(contains_insert, colliding);
+        bench_method!(try_extend_one, colliding);
+    }
+
+    group.finish();
+}
+
+const BENCH_SIZES: [usize; 3] = [100, 1000, 10000];
+
+macro_rules! define_bench {
+    ($fn_name:ident, $collection:ty, $sizes:expr, $gen_fn:expr) => {
+        fn $fn_name(c: &mut Criterion) {
+            bench_extend_one_comparison::<$collection, _, _>(c, $sizes, $gen_fn);
+        }
+    };
+}
+
+define_bench!(bench_hashset, HashSet<usize>, &BENCH_SIZES, gen_scalars);
+define_bench!(bench_btreeset, BTreeSet<usize>, &BENCH_SIZES, gen_scalars);
+define_bench!(bench_indexset, IndexSet<usize>, &BENCH_SIZES, gen_scalars);
+define_bench!(bench_indexmap, IndexMap<usize, usize>, &BENCH_SIZES, gen_pairs);
+
+criterion_group!(
+    name = try_extend_one;
+    config = Criterion::default();
+    targets = bench_hashset, bench_btreeset, bench_indexset, bench_indexmap
+);
+
+criterion_main!(try_extend_one);
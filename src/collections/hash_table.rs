@@ -0,0 +1,203 @@
+use core::fmt::{self, Debug};
+use core::ops::Deref;
+
+use hashbrown::HashTable;
+use hashbrown::hash_table::Entry;
+
+use crate::errors::{CollectError, Collision, ExtendError};
+use crate::{TryExtend, TryExtendOne, TryExtendSafe, TryReserve};
+
+/// The error returned when collecting an iterator into a [`HashTable`] fails due to a collision.
+pub type TableCollectError<I> = CollectError<I, HashTable<<I as Iterator>::Item>, Collision<<I as Iterator>::Item>>;
+
+/// A [`HashTable`] bundled with the hash and equality functions used to look up its items,
+/// rejecting items that are equal to an item already in the table.
+///
+/// [`HashTable`] does not store a hasher, so each operation needs the caller's hash and equality
+/// functions. This wrapper keeps them alongside the table, so that it can implement
+/// [`TryExtend`], [`TryExtendSafe`] and [`TryExtendOne`]. Colliding items are rejected with a
+/// [`Collision`] error, which preserves the rejected item.
+///
+/// The table is accessible via [`Deref`], but can not be mutated in place, as that could
+/// introduce a collision. To collect directly into a [`HashTable`], see
+/// [`TryCollectEx::try_collect_table`](crate::TryCollectEx::try_collect_table).
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the items in the table.
+/// - `H`: The hash function, `Fn(&T) -> u64`.
+/// - `E`: The equality function, `Fn(&T, &T) -> bool`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::collections::HashTableWith;
+/// # use collect_failable::{TryExtend, TryExtendOne};
+/// # use std::hash::BuildHasher;
+/// #[derive(Debug, PartialEq)]
+/// struct Record { id: u32, name: &'static str }
+///
+/// let state = hashbrown::DefaultHashBuilder::default();
+/// let mut table = HashTableWith::new(|record: &Record| state.hash_one(record.id), |a: &Record, b: &Record| a.id == b.id);
+///
+/// table.try_extend([Record { id: 1, name: "a" }, Record { id: 2, name: "b" }]).expect("should not collide");
+///
+/// let err = table.try_extend_one(Record { id: 1, name: "c" }).expect_err("should collide");
+/// assert_eq!(err.item, Record { id: 1, name: "c" });
+/// assert_eq!(table.len(), 2);
+/// ```
+pub struct HashTableWith<T, H, E> {
+    table: HashTable<T>,
+    hasher: H,
+    eq: E,
+}
+
+impl<T, H, E> HashTableWith<T, H, E>
+where
+    H: Fn(&T) -> u64,
+    E: Fn(&T, &T) -> bool,
+{
+    /// Creates a new, empty table using `hasher` and `eq` to look up items.
+    #[must_use]
+    pub fn new(hasher: H, eq: E) -> Self {
+        Self::with_capacity(0, hasher, eq)
+    }
+
+    /// Creates a new, empty table with space for at least `capacity` items.
+    #[must_use]
+    pub fn with_capacity(capacity: usize, hasher: H, eq: E) -> Self {
+        Self { table: HashTable::with_capacity(capacity), hasher, eq }
+    }
+
+    /// Returns `true` if the table contains an item equal to `item`.
+    pub fn contains(&self, item: &T) -> bool {
+        self.table.find((self.hasher)(item), |other| (self.eq)(item, other)).is_some()
+    }
+
+    /// Consumes the wrapper, returning the inner [`HashTable`].
+    #[must_use]
+    pub fn into_inner(self) -> HashTable<T> {
+        self.table
+    }
+}
+
+impl<T: Debug, H, E> Debug for HashTableWith<T, H, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HashTableWith").field("table", &self.table).finish_non_exhaustive()
+    }
+}
+
+impl<T, H, E> Deref for HashTableWith<T, H, E> {
+    type Target = HashTable<T>;
+
+    fn deref(&self) -> &HashTable<T> {
+        &self.table
+    }
+}
+
+impl<T, H, E> AsRef<HashTable<T>> for HashTableWith<T, H, E> {
+    fn as_ref(&self) -> &HashTable<T> {
+        &self.table
+    }
+}
+
+impl<T, H, E> IntoIterator for HashTableWith<T, H, E> {
+    type Item = T;
+    type IntoIter = hashbrown::hash_table::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.table.into_iter()
+    }
+}
+
+impl<T, H, E> TryExtendOne for HashTableWith<T, H, E>
+where
+    H: Fn(&T) -> u64,
+    E: Fn(&T, &T) -> bool,
+{
+    type Item = T;
+    type Error = Collision<T>;
+
+    /// Inserts `item` with a single lookup, failing if an equal item is already in the table.
+    fn try_extend_one(&mut self, item: T) -> Result<(), Self::Error> {
+        let hash = (self.hasher)(&item);
+
+        match self.table.entry(hash, |other| (self.eq)(&item, other), &self.hasher) {
+            Entry::Occupied(_) => Err(Collision::new(item)),
+            Entry::Vacant(entry) => {
+                entry.insert(item);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Extends the table, stopping at the first colliding item.
+impl<T, H, E, I> TryExtend<I> for HashTableWith<T, H, E>
+where
+    H: Fn(&T) -> u64,
+    E: Fn(&T, &T) -> bool,
+    I: IntoIterator<Item = T>,
+{
+    type Error = ExtendError<I::IntoIter, Collision<T>>;
+
+    fn try_extend(&mut self, iter: I) -> Result<(), Self::Error> {
+        let mut iter = iter.into_iter();
+        self.table.reserve(crate::prealloc::hint(&iter), &self.hasher);
+
+        crate::impls::macros::try_extend_basic(self, &mut iter)
+            .map_err(|(position, err)| ExtendError::new(iter, err).with_position(position))
+    }
+}
+
+/// Checks all items for collisions before extending the table.
+///
+/// Items are staged in a second [`HashTable`], which is returned in the error if an item collides.
+impl<T, H, E, I> TryExtendSafe<I> for HashTableWith<T, H, E>
+where
+    H: Fn(&T) -> u64,
+    E: Fn(&T, &T) -> bool,
+    I: IntoIterator<Item = T>,
+{
+    type Error = TableCollectError<I::IntoIter>;
+
+    fn try_extend_safe(&mut self, iter: I) -> Result<(), Self::Error> {
+        let mut iter = iter.into_iter();
+        let staging = HashTable::with_capacity(crate::prealloc::hint(&iter));
+
+        iter.try_fold(staging, |mut staging, item| {
+            let hash = (self.hasher)(&item);
+            let eq = |other: &T| (self.eq)(&item, other);
+
+            match self.table.find(hash, eq).is_some() || staging.find(hash, eq).is_some() {
+                true => Err((staging, Collision::new(item))),
+                false => {
+                    staging.insert_unique(hash, item, &self.hasher);
+                    Ok(staging)
+                }
+            }
+        })
+        .map(|staging| {
+            self.table.reserve(staging.len(), &self.hasher);
+            for item in staging {
+                self.table.insert_unique((self.hasher)(&item), item, &self.hasher);
+            }
+        })
+        .map_err(|(staging, err)| {
+            let position = staging.len();
+            CollectError::new(iter, staging, err).with_position(position)
+        })
+    }
+}
+
+impl<T, H, E> TryReserve for HashTableWith<T, H, E>
+where
+    H: Fn(&T) -> u64,
+{
+    type Error = hashbrown::TryReserveError;
+
+    /// Forwards directly to [`HashTable::try_reserve`].
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        self.table.try_reserve(additional, &self.hasher)
+    }
+}
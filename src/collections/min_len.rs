@@ -0,0 +1,218 @@
+use core::ops::Deref;
+
+use fluent_result::into::IntoResult;
+use tap::Pipe;
+
+use crate::errors::capacity::{FixedCap, RemainingCap};
+use crate::errors::types::SizeHint;
+use crate::errors::{CapacityError, CollectError, WrapperError};
+use crate::impls::macros::try_extend_basic;
+use crate::{InsertionOrder, TryExtend, TryExtendOne, TryExtendSafe, TryFromIterator};
+
+/// A collection that always contains at least `MIN` items.
+///
+/// Collecting into a [`MinLen`] collects into the inner collection `C` via
+/// [`TryExtendOne`], so items the inner collection rejects, such as a duplicate in a
+/// [`HashSet`](std::collections::HashSet), fail with a [`WrapperError::Inner`]. Once every
+/// item is collected, it fails with an [`Underflow`](crate::errors::capacity::CapacityErrorKind::Underflow)
+/// [`CapacityError`] in a [`WrapperError::Wrapper`] if it contains fewer than `MIN` items.
+///
+/// The inner collection is accessible via [`Deref`], but can not be mutated in
+/// place, as that could violate the length invariant. It may still be grown via
+/// [`Extend`], [`TryExtend`], [`TryExtendSafe`] or [`TryExtendOne`], which forward
+/// to the inner collection, since extending can only grow it, or unwrapped via
+/// [`MinLen::into_inner`]. It does not
+/// implement [`Default`], so it can not be built by methods that start from an empty
+/// collection, such as [`collect_partitioned`](crate::TryCollectEx::collect_partitioned).
+///
+/// # Type Parameters
+///
+/// - `C`: The inner collection.
+/// - `MIN`: The minimum number of items in the collection.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryCollectEx;
+/// # use collect_failable::collections::MinLen;
+/// # use collect_failable::errors::{Collision, WrapperError};
+/// # use collect_failable::errors::capacity::CapacityErrorKind;
+/// # use size_hinter::SizeHinter;
+/// # use std::collections::HashSet;
+/// let items: MinLen<Vec<i32>, 2> = (1..=3).try_collect_ex().expect("should have at least 2 items");
+/// assert_eq!(*items, vec![1, 2, 3]);
+///
+/// let err = [1].into_iter().hide_size().try_collect_ex::<MinLen<Vec<i32>, 2>>().expect_err("should be too short");
+/// assert!(matches!(err.error, WrapperError::Wrapper(ref error) if error.kind == CapacityErrorKind::Underflow { count: 1 }));
+///
+/// let err = [1, 1].into_iter().try_collect_ex::<MinLen<HashSet<i32>, 2>>().expect_err("should collide");
+/// assert_eq!(err.error, WrapperError::Inner(Collision::new(1)));
+/// assert_eq!(err.collected, HashSet::from([1]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MinLen<C, const MIN: usize> {
+    inner: C,
+}
+
+/// A collection that always contains at least one item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryCollectEx;
+/// # use collect_failable::collections::NonEmpty;
+/// # use std::collections::BTreeSet;
+/// let set: NonEmpty<BTreeSet<i32>> = [1, 2].into_iter().try_collect_ex().expect("should not be empty");
+/// assert_eq!(set.first(), Some(&1));
+///
+/// [0; 0].into_iter().try_collect_ex::<NonEmpty<BTreeSet<i32>>>().expect_err("should be empty");
+/// ```
+pub type NonEmpty<C> = MinLen<C, 1>;
+
+impl<C, const MIN: usize> MinLen<C, MIN> {
+    /// Wraps `collection`, failing if it contains fewer than `MIN` items.
+    ///
+    /// # Errors
+    ///
+    /// Returns `collection` if it contains fewer than `MIN` items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::collections::NonEmpty;
+    /// assert!(NonEmpty::<Vec<_>>::try_new(vec![1]).is_ok());
+    /// assert_eq!(NonEmpty::<Vec<i32>>::try_new(vec![]), Err(vec![]));
+    /// ```
+    pub fn try_new(collection: C) -> Result<Self, C>
+    where
+        for<'a> &'a C: IntoIterator<IntoIter: ExactSizeIterator>,
+    {
+        let len = (&collection).into_iter().len();
+        match len < MIN {
+            true => Err(collection),
+            false => Ok(Self { inner: collection }),
+        }
+    }
+
+    /// Consumes the wrapper, returning the inner collection.
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C, const MIN: usize> Deref for MinLen<C, MIN> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C, const MIN: usize> AsRef<C> for MinLen<C, MIN> {
+    fn as_ref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: IntoIterator, const MIN: usize> IntoIterator for MinLen<C, MIN> {
+    type Item = C::Item;
+    type IntoIter = C::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<T, C: Extend<T>, const MIN: usize> Extend<T> for MinLen<C, MIN> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<C: TryExtend<I>, I: IntoIterator, const MIN: usize> TryExtend<I> for MinLen<C, MIN> {
+    type Error = C::Error;
+
+    fn try_extend(&mut self, iter: I) -> Result<(), Self::Error> {
+        self.inner.try_extend(iter)
+    }
+}
+
+impl<C: TryExtendSafe<I>, I: IntoIterator, const MIN: usize> TryExtendSafe<I> for MinLen<C, MIN> {
+    type Error = C::Error;
+
+    fn try_extend_safe(&mut self, iter: I) -> Result<(), Self::Error> {
+        self.inner.try_extend_safe(iter)
+    }
+}
+
+impl<C: TryExtendOne, const MIN: usize> TryExtendOne for MinLen<C, MIN> {
+    type Item = C::Item;
+    type Error = C::Error;
+
+    fn try_extend_one(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        self.inner.try_extend_one(item)
+    }
+}
+
+impl<C: InsertionOrder, const MIN: usize> InsertionOrder for MinLen<C, MIN> {}
+
+impl<C, const MIN: usize> RemainingCap for MinLen<C, MIN> {
+    /// Returns [`SizeHint::unbounded(0)`](SizeHint::unbounded), since the minimum
+    /// length is already satisfied.
+    fn remaining_cap(&self) -> SizeHint {
+        SizeHint::unbounded(0)
+    }
+}
+
+impl<C, const MIN: usize> FixedCap for MinLen<C, MIN> {
+    /// Returns [`SizeHint::unbounded(MIN)`](SizeHint::unbounded).
+    const CAP: SizeHint = SizeHint::unbounded(MIN);
+}
+
+/// Create a [`MinLen`] collection from an iterator, failing if the inner collection
+/// rejects an item, or if it contains fewer than `MIN` items.
+impl<T, C, I, const MIN: usize> TryFromIterator<I> for MinLen<C, MIN>
+where
+    I: IntoIterator<Item = T>,
+    C: TryExtendOne<Item = T> + Default,
+    for<'a> &'a C: IntoIterator<IntoIter: ExactSizeIterator>,
+{
+    type Error = CollectError<I::IntoIter, C, WrapperError<CapacityError<T>, C::Error>>;
+
+    /// Create a [`MinLen`] collection from `into_iter`, failing if the inner collection
+    /// rejects an item, or if it contains fewer than `MIN` items.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] with:
+    ///
+    /// - a [`Bounds`](crate::errors::capacity::CapacityErrorKind::Bounds) [`CapacityError`] if
+    ///   `into_iter`'s [`size_hint`](Iterator::size_hint) indicates it can not produce `MIN` items.
+    /// - the inner collection's error and the item's position, if it rejects an item.
+    /// - an [`Underflow`](crate::errors::capacity::CapacityErrorKind::Underflow) [`CapacityError`]
+    ///   with the collected items, if the collection contains fewer than `MIN` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `into_iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+    fn try_from_iter(into_iter: I) -> Result<Self, Self::Error> {
+        let mut iter = into_iter.into_iter();
+        if let Err(error) = CapacityError::ensure_fits(&iter, Self::CAP) {
+            return CollectError::new(iter, C::default(), WrapperError::Wrapper(error)).into_err();
+        }
+
+        let mut inner = C::default();
+        if let Err((position, error)) = try_extend_basic(&mut inner, &mut iter) {
+            return CollectError::new(iter, inner, WrapperError::Inner(error)).with_position(position).into_err();
+        }
+
+        let count = (&inner).into_iter().len();
+        match count < MIN {
+            true => CapacityError::underflow(Self::CAP, count)
+                .pipe(|error| CollectError::new(iter, inner, WrapperError::Wrapper(error)))
+                .into_err(),
+            false => Ok(Self { inner }),
+        }
+    }
+}
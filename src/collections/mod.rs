@@ -0,0 +1,13 @@
+//! Collections that uphold an invariant, for use with failable collection.
+
+#[cfg(feature = "hashbrown")]
+mod hash_table;
+mod min_len;
+mod sorted;
+mod validated;
+
+#[cfg(feature = "hashbrown")]
+pub use hash_table::{HashTableWith, TableCollectError};
+pub use min_len::{MinLen, NonEmpty};
+pub use sorted::{SortedSet, SortedVec};
+pub use validated::{FromFn, Validated, Validator, from_fn};
@@ -0,0 +1,213 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Deref;
+
+use crate::errors::sorted::{SortedError, SortedErrorKind};
+
+/// A [`Vec`] whose items are always sorted in non-decreasing order.
+///
+/// Items that are less than the last item are rejected with a
+/// [`NotSorted`](SortedErrorKind::NotSorted) [`SortedError`]. Equal items are
+/// allowed, see [`SortedSet`] for a strictly increasing alternative.
+///
+/// The items are accessible as a slice via [`Deref`], but can not be mutated
+/// in place, as that could violate the ordering invariant.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryCollectEx;
+/// # use collect_failable::collections::SortedVec;
+/// # use collect_failable::errors::sorted::SortedErrorKind;
+/// let versions: SortedVec<_> = [1, 2, 2, 3].into_iter().try_collect_ex().expect("should be sorted");
+/// assert_eq!(*versions, [1, 2, 2, 3]);
+///
+/// let err = [1, 3, 2].into_iter().try_collect_ex::<SortedVec<_>>().expect_err("should not be sorted");
+/// assert_eq!(err.error.item, 2);
+/// assert_eq!(err.error.kind, SortedErrorKind::NotSorted);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SortedVec<T> {
+    items: Vec<T>,
+}
+
+/// A [`Vec`] whose items are always sorted in strictly increasing order.
+///
+/// Items that are less than the last item are rejected with a
+/// [`NotSorted`](SortedErrorKind::NotSorted) [`SortedError`], and items equal
+/// to the last item are rejected with a [`Collision`](SortedErrorKind::Collision)
+/// [`SortedError`]. As a result, all items are unique.
+///
+/// The items are accessible as a slice via [`Deref`], but can not be mutated
+/// in place, as that could violate the ordering invariant.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryCollectEx;
+/// # use collect_failable::collections::SortedSet;
+/// # use collect_failable::errors::sorted::SortedErrorKind;
+/// let timestamps: SortedSet<_> = [1, 2, 3].into_iter().try_collect_ex().expect("should be increasing");
+/// assert_eq!(*timestamps, [1, 2, 3]);
+///
+/// let err = [1, 2, 2].into_iter().try_collect_ex::<SortedSet<_>>().expect_err("should not be increasing");
+/// assert_eq!(err.error.item, 2);
+/// assert_eq!(err.error.kind, SortedErrorKind::Collision);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SortedSet<T> {
+    items: Vec<T>,
+}
+
+/// Implements the shared API and failable collection traits for a sorted collection.
+///
+/// # Arguments
+///
+/// * `type`: The sorted collection type.
+/// * `equal`: The [`SortedErrorKind`] for an item equal to the last item, if it is rejected.
+macro_rules! impl_sorted_collection {
+    (type: $type:ident; equal: $equal:expr) => {
+        impl<T> $type<T> {
+            /// Creates a new, empty collection.
+            #[must_use]
+            pub const fn new() -> Self {
+                Self { items: Vec::new() }
+            }
+
+            /// Creates a new, empty collection with at least the specified capacity.
+            #[must_use]
+            pub fn with_capacity(capacity: usize) -> Self {
+                Self { items: Vec::with_capacity(capacity) }
+            }
+
+            /// Removes the last item and returns it, or [`None`] if it is empty.
+            pub fn pop(&mut self) -> Option<T> {
+                self.items.pop()
+            }
+
+            /// Removes all items.
+            pub fn clear(&mut self) {
+                self.items.clear();
+            }
+
+            /// Consumes the collection, returning the sorted items as a [`Vec`].
+            #[must_use]
+            pub fn into_vec(self) -> Vec<T> {
+                self.items
+            }
+        }
+
+        impl<T: Ord> $type<T> {
+            /// Returns the reason `item` can not follow `last`, if any.
+            fn check(last: Option<&T>, item: &T) -> Option<SortedErrorKind> {
+                match last.map(|last| last.cmp(item)) {
+                    Some(Ordering::Greater) => Some(SortedErrorKind::NotSorted),
+                    Some(Ordering::Equal) => $equal,
+                    _ => None,
+                }
+            }
+        }
+
+        impl<T> $crate::InsertionOrder for $type<T> {}
+
+        impl<T> Default for $type<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T> Deref for $type<T> {
+            type Target = [T];
+
+            fn deref(&self) -> &[T] {
+                &self.items
+            }
+        }
+
+        impl<T> AsRef<[T]> for $type<T> {
+            fn as_ref(&self) -> &[T] {
+                &self.items
+            }
+        }
+
+        impl<T> From<$type<T>> for Vec<T> {
+            fn from(sorted: $type<T>) -> Self {
+                sorted.items
+            }
+        }
+
+        impl<T> IntoIterator for $type<T> {
+            type Item = T;
+            type IntoIter = alloc::vec::IntoIter<T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.items.into_iter()
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a $type<T> {
+            type Item = &'a T;
+            type IntoIter = core::slice::Iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.items.iter()
+            }
+        }
+
+        impl<T: Ord> $crate::TryExtendOne for $type<T> {
+            type Item = T;
+            type Error = SortedError<T>;
+
+            fn try_extend_one(&mut self, item: T) -> Result<(), Self::Error> {
+                match Self::check(self.items.last(), &item) {
+                    Some(kind) => Err(SortedError::new(item, kind)),
+                    None => {
+                        self.items.push(item);
+                        Ok(())
+                    }
+                }
+            }
+        }
+
+        impl<T: Ord, I> $crate::TryExtendSafe<I> for $type<T>
+        where
+            I: IntoIterator<Item = T>,
+        {
+            type Error = $crate::errors::CollectError<I::IntoIter, Self, SortedError<T>>;
+
+            fn try_extend_safe(&mut self, iter: I) -> Result<(), Self::Error> {
+                let mut iter = iter.into_iter();
+                let staging = Self::with_capacity($crate::prealloc::hint(&iter));
+
+                iter.try_fold(staging, |mut staging, item| {
+                    // the first staged item must follow the last item in the collection
+                    match Self::check(staging.items.last().or_else(|| self.items.last()), &item) {
+                        Some(kind) => Err((staging, SortedError::new(item, kind))),
+                        None => {
+                            staging.items.push(item);
+                            Ok(staging)
+                        }
+                    }
+                })
+                .map(|mut staging| self.items.append(&mut staging.items))
+                .map_err(|(staging, err)| {
+                    let position = staging.len();
+                    $crate::errors::CollectError::new(iter, staging, err).with_position(position)
+                })
+            }
+        }
+
+        $crate::impls::macros::impl_try_from_iter_via_try_extend_one!(
+            type: $type<T> where [T: Ord] of T;
+            ctor: |iter| Self::with_capacity($crate::prealloc::hint(iter))
+        );
+
+        $crate::impls::macros::impl_try_extend_via_try_extend_one!(
+            type: $type<T> where [T: Ord] of T;
+            reserve: |sorted, iter| sorted.items.reserve($crate::prealloc::hint(iter))
+        );
+    };
+}
+
+impl_sorted_collection!(type: SortedVec; equal: None);
+impl_sorted_collection!(type: SortedSet; equal: Some(SortedErrorKind::Collision));
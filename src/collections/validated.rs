@@ -0,0 +1,267 @@
+use alloc::vec::Vec;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use tap::Pipe;
+
+use crate::errors::invalid::Invalid;
+use crate::errors::{CollectError, ExtendError, WrapperError};
+use crate::{InsertionOrder, TryExtend, TryExtendOne, TryExtendSafe};
+
+/// A rule that items must satisfy before they are added to a [`Validated`] collection.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::collections::Validator;
+/// #[derive(Default)]
+/// struct Positive;
+///
+/// impl Validator for Positive {
+///     type Item = i32;
+///     type Error = &'static str;
+///
+///     fn validate(&self, item: &i32) -> Result<(), Self::Error> {
+///         if *item > 0 { Ok(()) } else { Err("not positive") }
+///     }
+/// }
+///
+/// assert_eq!(Positive.validate(&1), Ok(()));
+/// assert_eq!(Positive.validate(&-1), Err("not positive"));
+/// ```
+pub trait Validator {
+    /// The type of item validated.
+    type Item;
+
+    /// The error returned when an item fails validation.
+    type Error;
+
+    /// Validates `item`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Validator::Error`] if `item` is invalid.
+    fn validate(&self, item: &Self::Item) -> Result<(), Self::Error>;
+}
+
+impl<T, E> Validator for fn(&T) -> Result<(), E> {
+    type Item = T;
+    type Error = E;
+
+    fn validate(&self, item: &T) -> Result<(), E> {
+        self(item)
+    }
+}
+
+/// A [`Validator`] that calls a closure, created by [`from_fn`].
+pub struct FromFn<T, E, F> {
+    f: F,
+    marker: PhantomData<fn(&T) -> E>,
+}
+
+/// Creates a [`Validator`] from a closure.
+///
+/// Unlike a function pointer, the closure may capture its environment.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryExtendOne;
+/// # use collect_failable::collections::{Validated, from_fn};
+/// let max = 10;
+/// let mut items = Validated::<Vec<i32>, _>::new(from_fn(|item: &i32| if *item <= max { Ok(()) } else { Err("too large") }));
+///
+/// items.try_extend_one(10).expect("should be small enough");
+/// items.try_extend_one(11).expect_err("should be too large");
+/// assert_eq!(*items, [10]);
+/// ```
+pub const fn from_fn<T, E, F: Fn(&T) -> Result<(), E>>(f: F) -> FromFn<T, E, F> {
+    FromFn { f, marker: PhantomData }
+}
+
+impl<T, E, F: Fn(&T) -> Result<(), E>> Validator for FromFn<T, E, F> {
+    type Item = T;
+    type Error = E;
+
+    fn validate(&self, item: &T) -> Result<(), E> {
+        (self.f)(item)
+    }
+}
+
+impl<T, E, F: Clone> Clone for FromFn<T, E, F> {
+    fn clone(&self) -> Self {
+        Self { f: self.f.clone(), marker: PhantomData }
+    }
+}
+
+impl<T, E, F> Debug for FromFn<T, E, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FromFn").finish_non_exhaustive()
+    }
+}
+
+/// A collection whose items have all passed a [`Validator`].
+///
+/// Items are validated before they are added to the inner collection `C`, and
+/// items that fail validation are rejected with an [`Invalid`] error, which
+/// preserves the rejected item. Valid items are then added via the inner collection's
+/// own failable traits, so its invariants are upheld too. Both errors are reported in a
+/// [`WrapperError`].
+///
+/// The inner collection is accessible via [`Deref`], but can not be mutated in
+/// place, as that could violate the validation invariant.
+///
+/// # Type Parameters
+///
+/// - `C`: The inner collection.
+/// - `V`: The [`Validator`] items must satisfy. Collecting via
+///   [`TryFromIterator`](crate::TryFromIterator) requires `V` implement [`Default`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::{TryCollectEx, TryExtendOne};
+/// # use collect_failable::collections::{Validated, Validator};
+/// # use collect_failable::errors::WrapperError;
+/// # use collect_failable::errors::invalid::Invalid;
+/// #[derive(Debug, Default)]
+/// struct Ascii;
+///
+/// impl Validator for Ascii {
+///     type Item = String;
+///     type Error = &'static str;
+///
+///     fn validate(&self, item: &String) -> Result<(), Self::Error> {
+///         if item.is_ascii() { Ok(()) } else { Err("not ascii") }
+///     }
+/// }
+///
+/// let mut keys: Validated<Vec<_>, Ascii> = ["a", "b"].map(String::from).into_iter().try_collect_ex().expect("should be ascii");
+///
+/// let err = keys.try_extend_one(String::from("ü")).expect_err("should not be ascii");
+/// assert_eq!(err, WrapperError::Wrapper(Invalid::new(String::from("ü"), "not ascii")));
+/// assert_eq!(*keys, ["a", "b"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Validated<C, V> {
+    inner: C,
+    validator: V,
+}
+
+impl<C, V> Validated<C, V> {
+    /// Creates a new, empty collection using `validator`.
+    #[must_use]
+    pub fn new(validator: V) -> Self
+    where
+        C: Default,
+    {
+        Self { inner: C::default(), validator }
+    }
+
+    /// Returns the [`Validator`] used by the collection.
+    pub const fn validator(&self) -> &V {
+        &self.validator
+    }
+
+    /// Consumes the wrapper, returning the inner collection.
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C: Default, V: Default> Default for Validated<C, V> {
+    fn default() -> Self {
+        Self::new(V::default())
+    }
+}
+
+impl<C, V> Deref for Validated<C, V> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C, V> AsRef<C> for Validated<C, V> {
+    fn as_ref(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: IntoIterator, V> IntoIterator for Validated<C, V> {
+    type Item = C::Item;
+    type IntoIter = C::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<C: InsertionOrder, V> InsertionOrder for Validated<C, V> {}
+
+/// Validates `item`, then adds it via the inner collection's [`TryExtendOne`].
+impl<C: TryExtendOne<Item = V::Item>, V: Validator> TryExtendOne for Validated<C, V> {
+    type Item = V::Item;
+    type Error = WrapperError<Invalid<V::Item, V::Error>, C::Error>;
+
+    fn try_extend_one(&mut self, item: V::Item) -> Result<(), Self::Error> {
+        match self.validator.validate(&item) {
+            Ok(()) => self.inner.try_extend_one(item).map_err(WrapperError::Inner),
+            Err(error) => Err(WrapperError::Wrapper(Invalid::new(item, error))),
+        }
+    }
+}
+
+/// Extends the collection with the valid items, stopping at the first item that is
+/// invalid or rejected by the inner collection.
+impl<C: TryExtendOne<Item = V::Item>, V: Validator, I> TryExtend<I> for Validated<C, V>
+where
+    I: IntoIterator<Item = V::Item>,
+{
+    type Error = ExtendError<I::IntoIter, WrapperError<Invalid<V::Item, V::Error>, C::Error>>;
+
+    fn try_extend(&mut self, iter: I) -> Result<(), Self::Error> {
+        let mut iter = iter.into_iter();
+
+        crate::impls::macros::try_extend_basic(self, &mut iter)
+            .map_err(|(position, err)| ExtendError::new(iter, err).with_position(position))
+    }
+}
+
+/// Validates all items before extending the inner collection via its [`TryExtendSafe`].
+///
+/// Valid items are staged in a [`Vec`], which is returned in the error if an
+/// item fails validation. Otherwise, the staged items are passed to the inner
+/// collection, and its error is returned if it rejects them.
+impl<C: TryExtendSafe<Vec<V::Item>>, V: Validator, I> TryExtendSafe<I> for Validated<C, V>
+where
+    I: IntoIterator<Item = V::Item>,
+{
+    type Error = WrapperError<CollectError<I::IntoIter, Vec<V::Item>, Invalid<V::Item, V::Error>>, C::Error>;
+
+    fn try_extend_safe(&mut self, iter: I) -> Result<(), Self::Error> {
+        let mut iter = iter.into_iter();
+        let staging = Vec::with_capacity(crate::prealloc::hint(&iter));
+
+        iter.try_fold(staging, |mut staging, item| match self.validator.validate(&item) {
+            Ok(()) => {
+                staging.push(item);
+                Ok(staging)
+            }
+            Err(error) => Err((staging, Invalid::new(item, error))),
+        })
+        .map_err(|(staging, err)| {
+            let position = staging.len();
+            CollectError::new(iter, staging, err).with_position(position).pipe(WrapperError::Wrapper)
+        })
+        .and_then(|staging| self.inner.try_extend_safe(staging).map_err(WrapperError::Inner))
+    }
+}
+
+crate::impls::macros::impl_try_from_iter_via_try_extend_one!(
+    type: Validated<C, V> where [C: TryExtendOne<Item = V::Item> + Default, V: Validator + Default] of V::Item;
+    ctor: |_| Self::default()
+);
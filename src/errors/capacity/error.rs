@@ -1,13 +1,14 @@
 use crate::errors::capacity::FixedCap;
+use crate::errors::size_hint::SizeHintViolation;
 use crate::errors::types::SizeHint;
 
-use crate::errors::ErrorItemProvider;
+use crate::errors::{ErrorItemProvider, MapErrorItem};
 
 /// An error indicating that a collection or extension operation failed because
 /// of a conflict between the collection's capacity and the number of items
 /// produced by an [`Iterator`].
 ///
-/// This error represents three failure modes, identified by [`CapacityError::kind`].
+/// This error represents four failure modes, identified by [`CapacityError::kind`].
 ///
 /// - [`CapacityErrorKind::Bounds`] — the iterator’s reported size bounds are
 ///   incompatible with the required capacity, making the operation impossible
@@ -21,24 +22,32 @@ use crate::errors::ErrorItemProvider;
 ///   preserved, and may be recovered via [`ErrorItemProvider`]. The number of
 ///   overflowing items is implied to be at least one, but may be more.
 ///
+/// - [`CapacityErrorKind::SizeHint`] — the iterator violated its own size
+///   hint. Only reported by the strict collection methods, such as
+///   [`try_collect_strict`](crate::TryCollectEx::try_collect_strict).
+///
 /// # Type Parameters
 ///
 /// - `T`: The type of the item in the collection.
 #[subdef::subdef]
 #[derive(Debug, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[error("Collected items out of bounds ({capacity:?}): {kind}")]
 pub struct CapacityError<T> {
     /// The capacity constraint that was violated.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::errors::serialize::size_hint"))]
     pub capacity: SizeHint,
     /// The specific kind of capacity mismatch that occurred.
     pub kind: [CapacityErrorKind<T>; {
         /// Describes the specific type of capacity mismatch.
         #[derive(Debug, PartialEq, Eq, derive_more::Display)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize))]
         pub enum CapacityErrorKind<T> {
             /// The iterator's [`size_hint`](Iterator::size_hint) is incompatible with the required capacity.
             #[display("Iterator ({hint:?}) cannot satisfy capacity")]
             Bounds {
                 /// The iterator's [`size_hint`](Iterator::size_hint).
+                #[cfg_attr(feature = "serde", serde(serialize_with = "crate::errors::serialize::size_hint"))]
                 hint: SizeHint,
             },
             /// The iterator produced fewer items than the minimum required capacity.
@@ -53,6 +62,12 @@ pub struct CapacityError<T> {
                 /// The item that exceeded capacity.
                 overflow: T,
             },
+            /// The iterator reported an invalid [`size_hint`](Iterator::size_hint), or did not honor it.
+            #[display("{violation}")]
+            SizeHint {
+                /// How the iterator violated its [`size_hint`](Iterator::size_hint).
+                violation: SizeHintViolation,
+            },
         }
     }],
 }
@@ -206,6 +221,25 @@ impl<T> CapacityError<T> {
     pub const fn underflow_of<C: FixedCap>(count: usize) -> Self {
         Self::underflow(C::CAP, count)
     }
+
+    /// Creates a new [`SizeHint`](CapacityErrorKind::SizeHint) [`CapacityError`]
+    /// indicating that the iterator violated its size hint while filling `capacity`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::capacity::{CapacityError, CapacityErrorKind};
+    /// # use collect_failable::errors::size_hint::SizeHintViolation;
+    /// # use collect_failable::errors::types::SizeHint;
+    /// let violation = SizeHintViolation::underflow((3, None), 1);
+    /// let err = CapacityError::<i32>::size_hint(SizeHint::exact(3), violation);
+    ///
+    /// assert_eq!(err.kind, CapacityErrorKind::SizeHint { violation });
+    /// ```
+    #[must_use]
+    pub const fn size_hint(capacity: SizeHint, violation: SizeHintViolation) -> Self {
+        Self { capacity, kind: CapacityErrorKind::SizeHint { violation } }
+    }
 }
 
 impl<T> ErrorItemProvider for CapacityError<T> {
@@ -226,6 +260,20 @@ impl<T> ErrorItemProvider for CapacityError<T> {
     }
 }
 
+impl<T> MapErrorItem for CapacityError<T> {
+    type Mapped<U> = CapacityError<U>;
+
+    fn map_item<U, F: FnOnce(T) -> U>(self, f: F) -> CapacityError<U> {
+        let kind = match self.kind {
+            CapacityErrorKind::Bounds { hint } => CapacityErrorKind::Bounds { hint },
+            CapacityErrorKind::Underflow { count } => CapacityErrorKind::Underflow { count },
+            CapacityErrorKind::Overflow { overflow } => CapacityErrorKind::Overflow { overflow: f(overflow) },
+            CapacityErrorKind::SizeHint { violation } => CapacityErrorKind::SizeHint { violation },
+        };
+        CapacityError { capacity: self.capacity, kind }
+    }
+}
+
 #[cfg(feature = "arrayvec")]
 impl<T> From<arrayvec::CapacityError<T>> for CapacityError<T> {
     fn from(err: arrayvec::CapacityError<T>) -> Self {
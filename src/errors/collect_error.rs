@@ -52,6 +52,9 @@ pub struct CollectError<I, C, E> {
     #[cfg(doc)]
     /// The error that occurred.
     pub error: E,
+    #[cfg(doc)]
+    /// The zero-based index of the item in the input that caused the error, if known.
+    pub position: Option<usize>,
 
     /// The internal data of a [`CollectError`].
     #[cfg(all(not(doc), feature = "alloc"))]
@@ -69,6 +72,8 @@ pub struct CollectErrorData<I, C, E> {
     pub collected: C,
     /// The error that occurred.
     pub error: E,
+    /// The zero-based index of the item in the input that caused the error, if known.
+    pub position: Option<usize>,
 }
 
 impl<I, C, E> CollectError<I, C, E> {
@@ -98,7 +103,7 @@ impl<I, C, E> CollectError<I, C, E> {
     #[must_use]
     #[cfg(feature = "alloc")]
     pub fn new(remain: I, collected: C, error: E) -> Self {
-        CollectErrorData { remain, collected, error }.pipe(Box::new).pipe(|data| Self { data })
+        CollectErrorData { remain, collected, error, position: None }.pipe(Box::new).pipe(|data| Self { data })
     }
 
     /// Creates a new [`CollectError`].
@@ -129,7 +134,7 @@ impl<I, C, E> CollectError<I, C, E> {
     #[must_use]
     #[cfg(not(feature = "alloc"))]
     pub fn new(remain: I, collected: C, error: E) -> Self {
-        CollectErrorData { remain, collected, error }.pipe(|data| Self { data })
+        CollectErrorData { remain, collected, error, position: None }.pipe(|data| Self { data })
     }
 
     /// Consumes the error, returning a `CollectErrorData` containing the [`CollectError::remain`],
@@ -187,6 +192,34 @@ impl<I, C, E> CollectError<I, C, E> {
     pub fn into_data(self) -> CollectErrorData<I, C, E> {
         self.data
     }
+
+    /// Sets the zero-based index of the item in the input that caused the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::CollectError;
+    /// let error = CollectError::collision(3..5, vec![1, 2], 1).with_position(2);
+    ///
+    /// assert_eq!(error.position, Some(2));
+    /// ```
+    #[must_use]
+    pub const fn with_position(mut self, position: usize) -> Self {
+        self.data.position = Some(position);
+        self
+    }
+
+    /// Creates a new [`CollectError`] from its data, preserving its position.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_data(data: CollectErrorData<I, C, E>) -> Self {
+        data.pipe(Box::new).pipe(|data| Self { data })
+    }
+
+    /// Creates a new [`CollectError`] from its data, preserving its position.
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) const fn from_data(data: CollectErrorData<I, C, E>) -> Self {
+        Self { data }
+    }
 }
 
 /// Consumes the error and creates a new [Iterator] with the data it was
@@ -243,6 +276,7 @@ impl<I, C, E: Debug> Debug for CollectError<I, C, E> {
             .field_type::<I, Full>(name_of!(remain in Self))
             .field_type::<C, Short>(name_of!(collected in Self))
             .field(name_of!(error in Self), &self.error)
+            .field(name_of!(position in Self), &self.position)
             .finish()
     }
 }
@@ -264,6 +298,7 @@ impl<I, C, E: Debug> Debug for CollectErrorData<I, C, E> {
             .field_type::<I, Full>(name_of!(remain in Self))
             .field_type::<C, Short>(name_of!(collected in Self))
             .field(name_of!(error in Self), &self.error)
+            .field(name_of!(position in Self), &self.position)
             .finish()
     }
 }
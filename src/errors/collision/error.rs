@@ -1,4 +1,4 @@
-use crate::errors::ErrorItemProvider;
+use crate::errors::{ErrorItemProvider, MapErrorItem};
 
 /// Error type for when a single item cannot be added to a collection due to a collision.
 ///
@@ -16,6 +16,7 @@ use crate::errors::ErrorItemProvider;
 /// assert_eq!(error.item, 1);
 /// ```
 #[derive(Debug, PartialEq, Eq, thiserror::Error, derive_more::Constructor)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[error("item collision")]
 pub struct Collision<T> {
     /// The item that could not be inserted due to a collision.
@@ -33,3 +34,11 @@ impl<T> ErrorItemProvider for Collision<T> {
         Some(&self.item)
     }
 }
+
+impl<T> MapErrorItem for Collision<T> {
+    type Mapped<U> = Collision<U>;
+
+    fn map_item<U, F: FnOnce(T) -> U>(self, f: F) -> Collision<U> {
+        Collision::new(f(self.item))
+    }
+}
@@ -0,0 +1,155 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+
+use core::error::Error;
+use core::fmt::{Debug, Display};
+
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+use size_hinter::SizeHint;
+
+use crate::errors::capacity::{CapacityError, CapacityErrorKind};
+use crate::errors::sorted::{SortedError, SortedErrorKind};
+use crate::errors::{CollectError, Collision, ExtendError, Invalid, ResultCollectError};
+
+#[cfg(feature = "tuples")]
+use crate::errors::UnzipError;
+
+impl<T: Debug> Diagnostic for Collision<T> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new("collect_failable::collision"))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new("remove the duplicate key or use a merge policy"))
+    }
+}
+
+impl<T: Debug> Diagnostic for CapacityError<T> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self.kind {
+            CapacityErrorKind::Bounds { .. } => Some(Box::new("collect_failable::capacity::bounds")),
+            CapacityErrorKind::Underflow { .. } => Some(Box::new("collect_failable::capacity::underflow")),
+            CapacityErrorKind::Overflow { .. } => Some(Box::new("collect_failable::capacity::overflow")),
+            CapacityErrorKind::SizeHint { .. } => Some(Box::new("collect_failable::capacity::size_hint")),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let required = describe_capacity(self.capacity);
+        match self.kind {
+            CapacityErrorKind::Bounds { hint } => {
+                Some(Box::new(format!("the collection requires {required}, but the input produces {}", describe_capacity(hint))))
+            }
+            CapacityErrorKind::Underflow { count } => {
+                Some(Box::new(format!("the collection requires {required}, but the input produced {count}")))
+            }
+            CapacityErrorKind::Overflow { .. } => {
+                Some(Box::new(format!("the collection requires {required}, remove the excess items")))
+            }
+            CapacityErrorKind::SizeHint { .. } => {
+                Some(Box::new("the input's `size_hint` does not match the items it produces, fix its implementation"))
+            }
+        }
+    }
+}
+
+/// Describes a capacity in words, for use in help text.
+fn describe_capacity(capacity: SizeHint) -> String {
+    match capacity.as_hint() {
+        (lower, Some(upper)) if lower == upper => format!("exactly {lower} items"),
+        (0, Some(upper)) => format!("at most {upper} items"),
+        (lower, Some(upper)) => format!("between {lower} and {upper} items"),
+        (lower, None) => format!("at least {lower} items"),
+    }
+}
+
+impl<T: Debug> Diagnostic for SortedError<T> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self.kind {
+            SortedErrorKind::Collision => Some(Box::new("collect_failable::sorted::collision")),
+            SortedErrorKind::NotSorted => Some(Box::new("collect_failable::sorted::not_sorted")),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self.kind {
+            SortedErrorKind::Collision => Some(Box::new("remove the duplicate item")),
+            SortedErrorKind::NotSorted => Some(Box::new("sort the input, or collect without requiring sorted input")),
+        }
+    }
+}
+
+impl<T: Debug, E: Error + 'static> Diagnostic for Invalid<T, E> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new("collect_failable::invalid"))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new("remove or correct the invalid item"))
+    }
+}
+
+/// Implements [`Diagnostic`] for a wrapping error by forwarding every method to its
+/// `error` field, so the wrapper is rendered with the code, help and labels of the
+/// error that caused it.
+///
+/// If the wrapper knows the `position` of the rejected item, it is appended to the help
+/// text. A position counts items rather than bytes, so it is not reported as a label.
+macro_rules! forward_diagnostic {
+    () => {
+        fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+            self.error.code()
+        }
+
+        fn severity(&self) -> Option<Severity> {
+            self.error.severity()
+        }
+
+        fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+            with_position(self.error.help(), self.position)
+        }
+
+        fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+            self.error.url()
+        }
+
+        fn source_code(&self) -> Option<&dyn SourceCode> {
+            self.error.source_code()
+        }
+
+        fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+            self.error.labels()
+        }
+
+        fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+            self.error.related()
+        }
+    };
+}
+
+/// Appends the `position` of the rejected item, if known, to `help`.
+fn with_position<'a>(help: Option<Box<dyn Display + 'a>>, position: Option<usize>) -> Option<Box<dyn Display + 'a>> {
+    match (help, position) {
+        (Some(help), Some(position)) => Some(Box::new(format!("{help} (rejected item at position {position})"))),
+        (None, Some(position)) => Some(Box::new(format!("rejected item at position {position}"))),
+        (help, None) => help,
+    }
+}
+
+impl<I, C, E: Diagnostic + 'static> Diagnostic for CollectError<I, C, E> {
+    forward_diagnostic!();
+}
+
+impl<I, E: Diagnostic + 'static> Diagnostic for ExtendError<I, E> {
+    forward_diagnostic!();
+}
+
+impl<E: Diagnostic + 'static, C, CErr: Error, I> Diagnostic for ResultCollectError<E, C, CErr, I> {
+    forward_diagnostic!();
+}
+
+#[cfg(feature = "tuples")]
+impl<E: Diagnostic + 'static, Failed, Partial, Pending, I> Diagnostic for UnzipError<E, Failed, Partial, Pending, I> {
+    forward_diagnostic!();
+}
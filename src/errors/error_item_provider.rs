@@ -42,3 +42,25 @@ pub trait ErrorItemProvider {
     #[must_use]
     fn item(&self) -> Option<&Self::Item>;
 }
+
+/// Trait for error types whose rejected item, if any, can be mapped to a new type.
+///
+/// This allows the items of a [`CollectError`](super::CollectError) or
+/// [`ExtendError`](super::ExtendError) to be mapped, including the rejected item.
+///
+/// # Examples
+///
+/// ```
+/// use collect_failable::errors::{Collision, MapErrorItem};
+///
+/// let error = Collision::new(42).map_item(|item| item.to_string());
+/// assert_eq!(error.item, "42");
+/// ```
+pub trait MapErrorItem: ErrorItemProvider {
+    /// The type of this error, with its item mapped to `U`.
+    type Mapped<U>: ErrorItemProvider<Item = U>;
+
+    /// Consumes the error, mapping the rejected item, if any, with `f`.
+    #[must_use]
+    fn map_item<U, F: FnOnce(Self::Item) -> U>(self, f: F) -> Self::Mapped<U>;
+}
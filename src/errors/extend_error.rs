@@ -62,6 +62,9 @@ pub struct ExtendError<I, E> {
     #[cfg(doc)]
     /// The error that occurred.
     pub error: E,
+    #[cfg(doc)]
+    /// The zero-based index of the item in the input that caused the error, if known.
+    pub position: Option<usize>,
 
     #[cfg(all(not(doc), feature = "alloc"))]
     data: Box<ExtendErrorData<I, E>>,
@@ -76,6 +79,8 @@ pub struct ExtendErrorData<I, E> {
     pub remain: I,
     /// The error that occurred.
     pub error: E,
+    /// The zero-based index of the item in the input that caused the error, if known.
+    pub position: Option<usize>,
 }
 
 impl<I, E> ExtendError<I, E> {
@@ -101,7 +106,7 @@ impl<I, E> ExtendError<I, E> {
     #[must_use]
     #[cfg(feature = "alloc")]
     pub fn new(remain: I, error: E) -> Self {
-        ExtendErrorData { remain, error }.pipe(Box::new).pipe(|data| Self { data })
+        ExtendErrorData { remain, error, position: None }.pipe(Box::new).pipe(|data| Self { data })
     }
 
     /// Creates a new [`ExtendError`].
@@ -126,7 +131,7 @@ impl<I, E> ExtendError<I, E> {
     #[must_use]
     #[cfg(not(feature = "alloc"))]
     pub fn new(remain: I, error: E) -> Self {
-        ExtendErrorData { remain, error }.pipe(|data| Self { data })
+        ExtendErrorData { remain, error, position: None }.pipe(|data| Self { data })
     }
 
     /// Consumes the error, returning the internal data containing the
@@ -170,6 +175,34 @@ impl<I, E> ExtendError<I, E> {
     pub fn into_data(self) -> ExtendErrorData<I, E> {
         self.data
     }
+
+    /// Sets the zero-based index of the item in the input that caused the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::ExtendError;
+    /// let error = ExtendError::collision(2..4, 1).with_position(1);
+    ///
+    /// assert_eq!(error.position, Some(1));
+    /// ```
+    #[must_use]
+    pub const fn with_position(mut self, position: usize) -> Self {
+        self.data.position = Some(position);
+        self
+    }
+
+    /// Creates a new [`ExtendError`] from its data, preserving its position.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_data(data: ExtendErrorData<I, E>) -> Self {
+        data.pipe(Box::new).pipe(|data| Self { data })
+    }
+
+    /// Creates a new [`ExtendError`] from its data, preserving its position.
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) const fn from_data(data: ExtendErrorData<I, E>) -> Self {
+        Self { data }
+    }
 }
 
 impl<I: Iterator, E> IntoIterator for ExtendError<I, E>
@@ -203,6 +236,7 @@ impl<I, E: Debug> Debug for ExtendError<I, E> {
         f.debug_struct("ExtendError")
             .field_type::<I, Full>(name_of!(remain in Self))
             .field(name_of!(error in Self), &self.error)
+            .field(name_of!(position in Self), &self.position)
             .finish()
     }
 }
@@ -223,6 +257,7 @@ impl<I, E: Debug> Debug for ExtendErrorData<I, E> {
         f.debug_struct("ExtendErrorData")
             .field_type::<I, Full>(name_of!(remain in Self))
             .field(name_of!(error in Self), &self.error)
+            .field(name_of!(position in Self), &self.position)
             .finish()
     }
 }
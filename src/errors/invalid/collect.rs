@@ -0,0 +1,61 @@
+use crate::errors::CollectError;
+use crate::errors::invalid::error::Invalid;
+
+/// A [`CollectError`] for collection failures due to an item failing validation.
+pub type InvalidCollectError<I, C, E> = CollectError<I, C, Invalid<<I as Iterator>::Item, E>>;
+
+/// Specialization of [`CollectError`] for [`Invalid`].
+///
+/// This type is used when collection fails because an item failed validation.
+/// The [`Invalid`] will contain the rejected item, and the validation error.
+///
+/// # Type Parameters
+///
+/// - `I`: The type of the [Iterator] that was used to iterate the values.
+/// - `C`: The type of the collection that was used to collect the values.
+/// - `E`: The type of the validation error.
+///
+/// # Data Recovery
+///
+/// If `C` implements [`IntoIterator`], this type implements [`IntoIterator`]
+/// as well, allowing the data in the original iterator to be reconstructed from
+/// [`CollectError::remain`], [`CollectError::collected`], and the rejected item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::errors::CollectError;
+/// let error = CollectError::invalid(4..=4, vec![1, 2], -3, "not positive");
+///
+/// let values = error.into_iter().collect::<Vec<_>>();
+///
+/// assert_eq!(values, vec![-3, 1, 2, 4]);
+/// ```
+impl<I: Iterator, C, E> CollectError<I, C, Invalid<I::Item, E>> {
+    /// Creates a new [`CollectError`] with an [`Invalid`] error, for collection
+    /// failures due to an item failing validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The remaining [Iterator] after the invalid item
+    /// * `collected` - The values that were collected before the invalid item
+    /// * `item` - The item that failed validation
+    /// * `error` - The validation error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::CollectError;
+    /// let error = CollectError::invalid(4..=4, vec![1, 2], -3, "not positive");
+    ///
+    /// assert_eq!(error.remain, 4..=4);
+    /// assert_eq!(error.collected, vec![1, 2]);
+    /// assert_eq!(error.error.item, -3);
+    /// assert_eq!(error.error.error, "not positive");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn invalid(iter: I, collected: C, item: I::Item, error: E) -> Self {
+        Self::new(iter, collected, Invalid::new(item, error))
+    }
+}
@@ -0,0 +1,48 @@
+use crate::errors::{ErrorItemProvider, MapErrorItem};
+
+/// Error type for when a single item cannot be added to a collection because it
+/// failed validation.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the item that failed validation.
+/// - `E`: The type of the validation error.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::errors::invalid::Invalid;
+/// let error = Invalid::new(-1, "not positive");
+/// assert_eq!(error.item, -1);
+/// assert_eq!(error.error, "not positive");
+/// ```
+#[derive(Debug, PartialEq, Eq, thiserror::Error, derive_more::Constructor)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[error("item failed validation")]
+pub struct Invalid<T, E> {
+    /// The item that failed validation.
+    pub item: T,
+    /// The reason the item failed validation.
+    #[source]
+    pub error: E,
+}
+
+impl<T, E> ErrorItemProvider for Invalid<T, E> {
+    type Item = T;
+
+    fn into_item(self) -> Option<Self::Item> {
+        Some(self.item)
+    }
+
+    fn item(&self) -> Option<&Self::Item> {
+        Some(&self.item)
+    }
+}
+
+impl<T, E> MapErrorItem for Invalid<T, E> {
+    type Mapped<U> = Invalid<U, E>;
+
+    fn map_item<U, F: FnOnce(T) -> U>(self, f: F) -> Invalid<U, E> {
+        Invalid::new(f(self.item), self.error)
+    }
+}
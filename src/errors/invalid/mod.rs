@@ -0,0 +1,5 @@
+mod collect;
+mod error;
+
+pub use collect::InvalidCollectError;
+pub use error::Invalid;
@@ -0,0 +1,623 @@
+#[cfg(feature = "tuples")]
+use core::fmt::{Debug, Formatter};
+use core::iter::{Chain, Map};
+
+use crate::errors::{CollectError, CollectErrorData, ExtendError, ExtendErrorData, MapErrorItem};
+use crate::errors::{ResultCollectError, ResultCollectErrorData};
+
+#[cfg(feature = "tuples")]
+use crate::errors::{TupleExtendError, TupleExtendErrorData, UnzipError, UnzipErrorData};
+
+/// An [`Iterator`] that maps each side of a pair with a separate function.
+///
+/// Returned as the remaining iterator by the `map_left_items` and `map_right_items` methods of
+/// [`TupleExtendError`] and [`UnzipError`].
+#[cfg(feature = "tuples")]
+pub struct MapPairs<I, F, G> {
+    iter: I,
+    f: F,
+    g: G,
+}
+
+#[cfg(feature = "tuples")]
+impl<I: Debug, F, G> Debug for MapPairs<I, F, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MapPairs").field("iter", &self.iter).finish_non_exhaustive()
+    }
+}
+
+/// Error returned by [`UnzipError::map_left_items`] and [`UnzipError::map_right_items`], with
+/// the rejected item mapped to `U`.
+#[cfg(feature = "tuples")]
+pub type MappedUnzipError<E, U, Failed, Partial, Pending, I, F, G> =
+    UnzipError<<E as MapErrorItem>::Mapped<U>, Failed, Partial, Pending, MapPairs<I, F, G>>;
+
+#[cfg(feature = "tuples")]
+impl<I, F, G, A, B, U, V> Iterator for MapPairs<I, F, G>
+where
+    I: Iterator<Item = (A, B)>,
+    F: FnMut(A) -> U,
+    G: FnMut(B) -> V,
+{
+    type Item = (U, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(a, b)| ((self.f)(a), (self.g)(b)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, C, E> CollectError<I, C, E> {
+    /// Consumes the error, mapping the [`error`](CollectError::error) with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use std::collections::HashSet;
+    /// let err = HashSet::<i32>::try_from_iter([1, 2, 1]).expect_err("should collide");
+    /// let err = err.map_error(|collision| collision.item.to_string());
+    ///
+    /// assert_eq!(err.error, "1");
+    /// ```
+    #[must_use]
+    pub fn map_error<E2, F: FnOnce(E) -> E2>(self, f: F) -> CollectError<I, C, E2> {
+        let CollectErrorData { remain, collected, error, position } = self.into_data();
+        CollectError::from_data(CollectErrorData { remain, collected, error: f(error), position })
+    }
+
+    /// Consumes the error, mapping the [`collected`](CollectError::collected) values with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use std::collections::HashSet;
+    /// let err = HashSet::<i32>::try_from_iter([1, 2, 1]).expect_err("should collide");
+    /// let err = err.map_collected(|set| set.len());
+    ///
+    /// assert_eq!(err.collected, 2);
+    /// ```
+    #[must_use]
+    pub fn map_collected<C2, F: FnOnce(C) -> C2>(self, f: F) -> CollectError<I, C2, E> {
+        let CollectErrorData { remain, collected, error, position } = self.into_data();
+        CollectError::from_data(CollectErrorData { remain, collected: f(collected), error, position })
+    }
+
+    /// Consumes the error, mapping the [`remain`](CollectError::remain) iterator with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use std::collections::HashSet;
+    /// let err = HashSet::<i32>::try_from_iter([1, 2, 1, 3, 4]).expect_err("should collide");
+    /// let err = err.map_remain(|remain| remain.collect::<Vec<_>>());
+    ///
+    /// assert_eq!(err.remain, [3, 4]);
+    /// ```
+    #[must_use]
+    pub fn map_remain<I2, F: FnOnce(I) -> I2>(self, f: F) -> CollectError<I2, C, E> {
+        let CollectErrorData { remain, collected, error, position } = self.into_data();
+        CollectError::from_data(CollectErrorData { remain: f(remain), collected, error, position })
+    }
+}
+
+impl<I: Iterator, C, E> CollectError<I, C, E>
+where
+    C: IntoIterator<Item = I::Item>,
+    E: MapErrorItem<Item = I::Item>,
+{
+    /// Consumes the error, mapping every item it holds with `f`.
+    ///
+    /// The rejected item in the [`error`](CollectError::error) and the
+    /// [`collected`](CollectError::collected) values are mapped eagerly, the latter being
+    /// collected into `C2`. The [`remain`](CollectError::remain) iterator is mapped lazily.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use std::collections::BTreeSet;
+    /// let err = BTreeSet::<i32>::try_from_iter([1, 2, 1, 3]).expect_err("should collide");
+    /// let err = err.map_items::<_, Vec<_>, _>(|n| n * 10);
+    ///
+    /// assert_eq!(err.error.item, 10);
+    /// assert_eq!(err.collected, [10, 20]);
+    /// assert_eq!(err.into_iter().collect::<Vec<_>>(), [10, 10, 20, 30], "no items should be lost");
+    /// ```
+    #[must_use]
+    pub fn map_items<U, C2, F>(self, mut f: F) -> CollectError<Map<I, F>, C2, E::Mapped<U>>
+    where
+        C2: FromIterator<U>,
+        F: FnMut(I::Item) -> U,
+    {
+        let CollectErrorData { remain, collected, error, position } = self.into_data();
+        let error = error.map_item(&mut f);
+        let collected = collected.into_iter().map(&mut f).collect();
+        CollectError::from_data(CollectErrorData { remain: remain.map(f), collected, error, position })
+    }
+}
+
+/// Converts a [`CollectError`] into an [`ExtendError`], folding the
+/// [`collected`](CollectError::collected) values back into the front of the remaining iterator.
+///
+/// Iterating the resulting error yields the same items, in the same order, as iterating
+/// the original [`CollectError`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryFromIterator;
+/// # use collect_failable::errors::ExtendError;
+/// # use std::collections::BTreeSet;
+/// let err = BTreeSet::<i32>::try_from_iter([1, 2, 1, 3]).expect_err("should collide");
+/// let err = ExtendError::from(err);
+///
+/// assert_eq!(err.error.item, 1);
+/// assert_eq!(err.into_iter().collect::<Vec<_>>(), [1, 1, 2, 3], "no items should be lost");
+/// ```
+impl<I: Iterator, C, E> From<CollectError<I, C, E>> for ExtendError<Chain<C::IntoIter, I>, E>
+where
+    C: IntoIterator<Item = I::Item>,
+{
+    fn from(err: CollectError<I, C, E>) -> Self {
+        let CollectErrorData { remain, collected, error, position } = err.into_data();
+        Self::from_data(ExtendErrorData { remain: collected.into_iter().chain(remain), error, position })
+    }
+}
+
+impl<I, E> ExtendError<I, E> {
+    /// Consumes the error, mapping the [`error`](ExtendError::error) with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut set = HashSet::from([1]);
+    /// let err = set.try_extend([2, 1]).expect_err("should collide").map_error(|collision| collision.item);
+    ///
+    /// assert_eq!(err.error, 1);
+    /// ```
+    #[must_use]
+    pub fn map_error<E2, F: FnOnce(E) -> E2>(self, f: F) -> ExtendError<I, E2> {
+        let ExtendErrorData { remain, error, position } = self.into_data();
+        ExtendError::from_data(ExtendErrorData { remain, error: f(error), position })
+    }
+
+    /// Consumes the error, mapping the [`remain`](ExtendError::remain) iterator with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut set = HashSet::from([1]);
+    /// let err = set.try_extend([1, 2, 3]).expect_err("should collide").map_remain(Iterator::count);
+    ///
+    /// assert_eq!(err.remain, 2);
+    /// ```
+    #[must_use]
+    pub fn map_remain<I2, F: FnOnce(I) -> I2>(self, f: F) -> ExtendError<I2, E> {
+        let ExtendErrorData { remain, error, position } = self.into_data();
+        ExtendError::from_data(ExtendErrorData { remain: f(remain), error, position })
+    }
+}
+
+impl<I: Iterator, E> ExtendError<I, E>
+where
+    E: MapErrorItem<Item = I::Item>,
+{
+    /// Consumes the error, mapping every item it holds with `f`.
+    ///
+    /// The rejected item in the [`error`](ExtendError::error) is mapped eagerly, and the
+    /// [`remain`](ExtendError::remain) iterator is mapped lazily.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut set = HashSet::from([1]);
+    /// let err = set.try_extend([1, 2, 3]).expect_err("should collide").map_items(|n| n * 10);
+    ///
+    /// assert_eq!(err.error.item, 10);
+    /// assert_eq!(err.into_iter().collect::<Vec<_>>(), [10, 20, 30]);
+    /// ```
+    #[must_use]
+    pub fn map_items<U, F: FnMut(I::Item) -> U>(self, mut f: F) -> ExtendError<Map<I, F>, E::Mapped<U>> {
+        let ExtendErrorData { remain, error, position } = self.into_data();
+        let error = error.map_item(&mut f);
+        ExtendError::from_data(ExtendErrorData { remain: remain.map(f), error, position })
+    }
+}
+
+/// There is no `map_items` for [`ResultCollectError`]: a failed collection's error `CErr` is any
+/// [`TryFromIterator::Error`](crate::TryFromIterator::Error), so the items it holds can not be
+/// mapped generically. Use [`map_result`](ResultCollectError::map_result) and
+/// [`map_iter`](ResultCollectError::map_iter) instead.
+impl<E, C, CErr, I> ResultCollectError<E, C, CErr, I> {
+    /// Consumes the error, mapping the [`error`](ResultCollectError::error) with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use std::collections::HashSet;
+    /// let data = [Ok(1), Err("bad"), Ok(2)];
+    /// let err = Result::<HashSet<i32>, _>::try_from_iter(data).expect_err("should fail").map_error(str::len);
+    ///
+    /// assert_eq!(err.error, 3);
+    /// ```
+    #[must_use]
+    pub fn map_error<E2, F: FnOnce(E) -> E2>(self, f: F) -> ResultCollectError<E2, C, CErr, I> {
+        let ResultCollectErrorData { error, result, iter, position } = self.into_data();
+        ResultCollectError::from_data(ResultCollectErrorData { error: f(error), result, iter, position })
+    }
+
+    /// Consumes the error, mapping the [`result`](ResultCollectError::result) with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use std::collections::HashSet;
+    /// let data = [Ok(1), Err("bad"), Ok(2)];
+    /// let err = Result::<HashSet<i32>, _>::try_from_iter(data).expect_err("should fail");
+    /// let err = err.map_result(|result| result.map(|set| set.len()).map_err(|err| err.into_owned()));
+    ///
+    /// assert_eq!(err.result.as_ref().ok(), Some(&1));
+    /// ```
+    #[must_use]
+    pub fn map_result<C2, CErr2, F>(self, f: F) -> ResultCollectError<E, C2, CErr2, I>
+    where
+        F: FnOnce(Result<C, CErr>) -> Result<C2, CErr2>,
+    {
+        let ResultCollectErrorData { error, result, iter, position } = self.into_data();
+        ResultCollectError::from_data(ResultCollectErrorData { error, result: f(result), iter, position })
+    }
+
+    /// Consumes the error, mapping the remaining [`iter`](ResultCollectError::iter) with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use std::collections::HashSet;
+    /// let data = [Ok(1), Err("bad"), Ok(2), Ok(3)];
+    /// let err = Result::<HashSet<i32>, _>::try_from_iter(data).expect_err("should fail").map_iter(Iterator::count);
+    ///
+    /// assert_eq!(err.iter, 2);
+    /// ```
+    #[must_use]
+    pub fn map_iter<I2, F: FnOnce(I) -> I2>(self, f: F) -> ResultCollectError<E, C, CErr, I2> {
+        let ResultCollectErrorData { error, result, iter, position } = self.into_data();
+        ResultCollectError::from_data(ResultCollectErrorData { error, result, iter: f(iter), position })
+    }
+}
+
+#[cfg(feature = "tuples")]
+impl<E, P, I> TupleExtendError<E, P, I> {
+    /// Consumes the error, mapping the [`error`](TupleExtendError::error) with `f`.
+    ///
+    /// Useful for normalizing the [`Either`](crate::errors::types::Either) error of a tuple
+    /// extension into a single type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut sets = (HashSet::from([1]), HashSet::from(['a']));
+    /// let err = sets.try_extend([(2, 'b'), (1, 'c'), (3, 'd')]).expect_err("should collide").unwrap_left();
+    /// let err = err.map_error(|collision| collision.item);
+    ///
+    /// assert_eq!(err.error, 1);
+    /// ```
+    #[must_use]
+    pub fn map_error<E2, F: FnOnce(E) -> E2>(self, f: F) -> TupleExtendError<E2, P, I> {
+        let TupleExtendErrorData { error, pending, remaining, position } = self.into_data();
+        TupleExtendError::from_data(TupleExtendErrorData { error: f(error), pending, remaining, position })
+    }
+
+    /// Consumes the error, mapping the [`pending`](TupleExtendError::pending) item, if any, with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut sets = (HashSet::from([1]), HashSet::from(['a']));
+    /// let err = sets.try_extend([(2, 'b'), (1, 'c'), (3, 'd')]).expect_err("should collide").unwrap_left();
+    /// let err = err.map_pending(|c| c.to_ascii_uppercase());
+    ///
+    /// assert_eq!(err.pending, Some('C'));
+    /// ```
+    #[must_use]
+    pub fn map_pending<P2, F: FnOnce(P) -> P2>(self, f: F) -> TupleExtendError<E, P2, I> {
+        let TupleExtendErrorData { error, pending, remaining, position } = self.into_data();
+        TupleExtendError::from_data(TupleExtendErrorData { error, pending: pending.map(f), remaining, position })
+    }
+
+    /// Consumes the error, mapping the [`remaining`](TupleExtendError::remaining) iterator with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut sets = (HashSet::from([1]), HashSet::from(['a']));
+    /// let err = sets.try_extend([(2, 'b'), (1, 'c'), (3, 'd')]).expect_err("should collide").unwrap_left();
+    /// let err = err.map_remaining(Iterator::count);
+    ///
+    /// assert_eq!(err.remaining, 1);
+    /// ```
+    #[must_use]
+    pub fn map_remaining<I2, F: FnOnce(I) -> I2>(self, f: F) -> TupleExtendError<E, P, I2> {
+        let TupleExtendErrorData { error, pending, remaining, position } = self.into_data();
+        TupleExtendError::from_data(TupleExtendErrorData { error, pending, remaining: f(remaining), position })
+    }
+}
+
+#[cfg(feature = "tuples")]
+impl<E, Failed, Partial, Pending, I> UnzipError<E, Failed, Partial, Pending, I> {
+    /// Consumes the error, mapping the [`error`](UnzipError::error) with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryUnzip;
+    /// # use std::collections::HashSet;
+    /// let pairs = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd')];
+    /// let err = pairs.into_iter().try_unzip::<HashSet<_>, HashSet<_>>().expect_err("should collide").unwrap_left();
+    /// let err = err.map_error(|collision| collision.item);
+    ///
+    /// assert_eq!(err.error, 1);
+    /// ```
+    #[must_use]
+    pub fn map_error<E2, F: FnOnce(E) -> E2>(self, f: F) -> UnzipError<E2, Failed, Partial, Pending, I> {
+        let UnzipErrorData { error, failed, partial, pending, remaining, position } = self.into_data();
+        UnzipError::from_data(UnzipErrorData { error: f(error), failed, partial, pending, remaining, position })
+    }
+
+    /// Consumes the error, mapping the [`failed`](UnzipError::failed) collection with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryUnzip;
+    /// # use std::collections::HashSet;
+    /// let pairs = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd')];
+    /// let err = pairs.into_iter().try_unzip::<HashSet<_>, HashSet<_>>().expect_err("should collide").unwrap_left();
+    /// let err = err.map_failed(|set| set.contains(&2));
+    ///
+    /// assert!(err.failed);
+    /// ```
+    #[must_use]
+    pub fn map_failed<Failed2, F: FnOnce(Failed) -> Failed2>(self, f: F) -> UnzipError<E, Failed2, Partial, Pending, I> {
+        let UnzipErrorData { error, failed, partial, pending, remaining, position } = self.into_data();
+        UnzipError::from_data(UnzipErrorData { error, failed: f(failed), partial, pending, remaining, position })
+    }
+
+    /// Consumes the error, mapping the [`partial`](UnzipError::partial) collection with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryUnzip;
+    /// # use std::collections::HashSet;
+    /// let pairs = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd')];
+    /// let err = pairs.into_iter().try_unzip::<HashSet<_>, HashSet<_>>().expect_err("should collide").unwrap_left();
+    /// let err = err.map_partial(|set| set.len());
+    ///
+    /// assert_eq!(err.partial, 2);
+    /// ```
+    #[must_use]
+    pub fn map_partial<Partial2, F: FnOnce(Partial) -> Partial2>(self, f: F) -> UnzipError<E, Failed, Partial2, Pending, I> {
+        let UnzipErrorData { error, failed, partial, pending, remaining, position } = self.into_data();
+        UnzipError::from_data(UnzipErrorData { error, failed, partial: f(partial), pending, remaining, position })
+    }
+
+    /// Consumes the error, mapping the [`pending`](UnzipError::pending) item, if any, with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryUnzip;
+    /// # use std::collections::HashSet;
+    /// let pairs = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd')];
+    /// let err = pairs.into_iter().try_unzip::<HashSet<_>, HashSet<_>>().expect_err("should collide").unwrap_left();
+    /// let err = err.map_pending(|c| c.to_ascii_uppercase());
+    ///
+    /// assert_eq!(err.pending, Some('C'));
+    /// ```
+    #[must_use]
+    pub fn map_pending<Pending2, F: FnOnce(Pending) -> Pending2>(self, f: F) -> UnzipError<E, Failed, Partial, Pending2, I> {
+        let UnzipErrorData { error, failed, partial, pending, remaining, position } = self.into_data();
+        UnzipError::from_data(UnzipErrorData { error, failed, partial, pending: pending.map(f), remaining, position })
+    }
+
+    /// Consumes the error, mapping the [`remaining`](UnzipError::remaining) iterator with `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryUnzip;
+    /// # use std::collections::HashSet;
+    /// let pairs = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd')];
+    /// let err = pairs.into_iter().try_unzip::<HashSet<_>, HashSet<_>>().expect_err("should collide").unwrap_left();
+    /// let err = err.map_remaining(Iterator::count);
+    ///
+    /// assert_eq!(err.remaining, 1);
+    /// ```
+    #[must_use]
+    pub fn map_remaining<I2, F: FnOnce(I) -> I2>(self, f: F) -> UnzipError<E, Failed, Partial, Pending, I2> {
+        let UnzipErrorData { error, failed, partial, pending, remaining, position } = self.into_data();
+        UnzipError::from_data(UnzipErrorData { error, failed, partial, pending, remaining: f(remaining), position })
+    }
+}
+
+#[cfg(feature = "tuples")]
+impl<E: MapErrorItem, P, I: Iterator> TupleExtendError<E, P, I> {
+    /// Consumes an error from the [`Left`](either::Either::Left) side of a tuple extension
+    /// failure, mapping every item it holds, with `f` for the first items of the pairs, and `g`
+    /// for the second.
+    ///
+    /// The rejected item in the [`error`](TupleExtendError::error) and the
+    /// [`pending`](TupleExtendError::pending) item are mapped eagerly, and the
+    /// [`remaining`](TupleExtendError::remaining) iterator is mapped lazily.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut sets = (HashSet::from([1]), HashSet::from(['a']));
+    /// let err = sets.try_extend([(2, 'b'), (1, 'c'), (3, 'd')]).expect_err("should collide").unwrap_left();
+    /// let err = err.map_left_items(|n| n * 10, |c| c.to_ascii_uppercase());
+    ///
+    /// assert_eq!((err.error.item, err.pending), (10, Some('C')));
+    /// assert_eq!(err.into_left_iter().collect::<Vec<_>>(), [(10, 'C'), (30, 'D')], "no items should be lost");
+    /// ```
+    #[must_use]
+    pub fn map_left_items<U, V, F, G>(self, mut f: F, mut g: G) -> TupleExtendError<E::Mapped<U>, V, MapPairs<I, F, G>>
+    where
+        I: Iterator<Item = (E::Item, P)>,
+        F: FnMut(E::Item) -> U,
+        G: FnMut(P) -> V,
+    {
+        let TupleExtendErrorData { error, pending, remaining, position } = self.into_data();
+        let error = error.map_item(&mut f);
+        let pending = pending.map(&mut g);
+        let remaining = MapPairs { iter: remaining, f, g };
+        TupleExtendError::from_data(TupleExtendErrorData { error, pending, remaining, position })
+    }
+
+    /// Consumes an error from the [`Right`](either::Either::Right) side of a tuple extension
+    /// failure, mapping every item it holds, with `f` for the first items of the pairs, and `g`
+    /// for the second.
+    ///
+    /// This is the mirror of [`TupleExtendError::map_left_items`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut sets = (HashSet::from([1]), HashSet::from(['a']));
+    /// let err = sets.try_extend([(2, 'b'), (3, 'a'), (4, 'd')]).expect_err("should collide").unwrap_right();
+    /// let (rejected, remaining) = err.map_right_items(|n| n * 10, |c| c.to_ascii_uppercase()).into_right_parts();
+    ///
+    /// assert_eq!(rejected, Some('A'));
+    /// assert_eq!(remaining.collect::<Vec<_>>(), [(40, 'D')]);
+    /// ```
+    #[must_use]
+    pub fn map_right_items<U, V, F, G>(self, mut f: F, mut g: G) -> TupleExtendError<E::Mapped<V>, U, MapPairs<I, F, G>>
+    where
+        I: Iterator<Item = (P, E::Item)>,
+        F: FnMut(P) -> U,
+        G: FnMut(E::Item) -> V,
+    {
+        let TupleExtendErrorData { error, pending, remaining, position } = self.into_data();
+        let error = error.map_item(&mut g);
+        let pending = pending.map(&mut f);
+        let remaining = MapPairs { iter: remaining, f, g };
+        TupleExtendError::from_data(TupleExtendErrorData { error, pending, remaining, position })
+    }
+}
+
+#[cfg(feature = "tuples")]
+impl<E, Failed, Partial, Pending, I> UnzipError<E, Failed, Partial, Pending, I>
+where
+    E: MapErrorItem,
+    Failed: IntoIterator<Item = E::Item>,
+    Partial: IntoIterator<Item = Pending>,
+    I: Iterator,
+{
+    /// Consumes an error from the [`Left`](either::Either::Left) side of a
+    /// [`TryUnzip`](crate::TryUnzip) failure, mapping every item it holds, with `f` for the first
+    /// items of the pairs, and `g` for the second.
+    ///
+    /// The rejected item in the [`error`](UnzipError::error), the
+    /// [`failed`](UnzipError::failed) and [`partial`](UnzipError::partial) collections, and the
+    /// [`pending`](UnzipError::pending) item are mapped eagerly, the collections being collected
+    /// into `Failed2` and `Partial2`. The [`remaining`](UnzipError::remaining) iterator is mapped
+    /// lazily.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryUnzip;
+    /// # use indexmap::IndexSet;
+    /// let pairs = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd')];
+    /// let err = pairs.into_iter().try_unzip::<IndexSet<_>, Vec<_>>().expect_err("should collide").unwrap_left();
+    /// let err = err.map_left_items::<_, _, Vec<_>, Vec<_>, _, _>(|n| n * 10, |c| c.to_ascii_uppercase());
+    ///
+    /// assert_eq!((err.failed.as_slice(), err.partial.as_slice()), ([10, 20].as_slice(), ['A', 'B'].as_slice()));
+    /// assert_eq!(err.into_left_iter().collect::<Vec<_>>(), [(10, 'A'), (20, 'B'), (10, 'C'), (30, 'D')]);
+    /// ```
+    #[must_use]
+    pub fn map_left_items<U, V, Failed2, Partial2, F, G>(
+        self,
+        mut f: F,
+        mut g: G,
+    ) -> MappedUnzipError<E, U, Failed2, Partial2, V, I, F, G>
+    where
+        Failed2: FromIterator<U>,
+        Partial2: FromIterator<V>,
+        I: Iterator<Item = (E::Item, Pending)>,
+        F: FnMut(E::Item) -> U,
+        G: FnMut(Pending) -> V,
+    {
+        let UnzipErrorData { error, failed, partial, pending, remaining, position } = self.into_data();
+        let error = error.map_item(&mut f);
+        let failed = failed.into_iter().map(&mut f).collect();
+        let partial = partial.into_iter().map(&mut g).collect();
+        let pending = pending.map(&mut g);
+        let remaining = MapPairs { iter: remaining, f, g };
+        UnzipError::from_data(UnzipErrorData { error, failed, partial, pending, remaining, position })
+    }
+
+    /// Consumes an error from the [`Right`](either::Either::Right) side of a
+    /// [`TryUnzip`](crate::TryUnzip) failure, mapping every item it holds, with `f` for the first
+    /// items of the pairs, and `g` for the second.
+    ///
+    /// This is the mirror of [`UnzipError::map_left_items`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryUnzip;
+    /// # use indexmap::IndexSet;
+    /// let pairs = [(1, 'a'), (2, 'b'), (3, 'a'), (4, 'd')];
+    /// let err = pairs.into_iter().try_unzip::<Vec<_>, IndexSet<_>>().expect_err("should collide").unwrap_right();
+    /// let err = err.map_right_items::<_, _, Vec<_>, Vec<_>, _, _>(|n| n * 10, |c| c.to_ascii_uppercase());
+    ///
+    /// assert_eq!(err.into_right_iter().collect::<Vec<_>>(), [(10, 'A'), (20, 'B'), (30, 'A'), (40, 'D')]);
+    /// ```
+    #[must_use]
+    pub fn map_right_items<U, V, Failed2, Partial2, F, G>(
+        self,
+        mut f: F,
+        mut g: G,
+    ) -> MappedUnzipError<E, V, Failed2, Partial2, U, I, F, G>
+    where
+        Failed2: FromIterator<V>,
+        Partial2: FromIterator<U>,
+        I: Iterator<Item = (Pending, E::Item)>,
+        F: FnMut(Pending) -> U,
+        G: FnMut(E::Item) -> V,
+    {
+        let UnzipErrorData { error, failed, partial, pending, remaining, position } = self.into_data();
+        let error = error.map_item(&mut g);
+        let failed = failed.into_iter().map(&mut g).collect();
+        let partial = partial.into_iter().map(&mut f).collect();
+        let pending = pending.map(&mut f);
+        let remaining = MapPairs { iter: remaining, f, g };
+        UnzipError::from_data(UnzipErrorData { error, failed, partial, pending, remaining, position })
+    }
+}
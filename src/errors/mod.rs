@@ -6,11 +6,33 @@ pub mod capacity;
 /// Errors relating to collections with collisions.
 pub mod collision;
 
+/// Errors relating to collections with validated items.
+pub mod invalid;
+
+/// Errors relating to collections built from sorted input.
+pub mod sorted;
+
+/// Errors relating to iterators that violate their size hint.
+pub mod size_hint;
+
 mod error_item_provider;
 
 mod collect_error;
 mod extend_error;
 mod result_collection_error;
+mod wrapper_error;
+
+mod map;
+mod resume;
+
+#[cfg(feature = "alloc")]
+mod owned;
+
+#[cfg(feature = "miette")]
+mod diagnostic;
+
+#[cfg(feature = "serde")]
+mod serialize;
 
 #[cfg(feature = "tuples")]
 mod tuple_extend_error;
@@ -22,10 +44,21 @@ pub use error_item_provider::*;
 pub use collect_error::*;
 pub use extend_error::*;
 pub use result_collection_error::*;
+pub use wrapper_error::*;
+
+#[cfg(feature = "alloc")]
+pub use owned::*;
+#[cfg(feature = "alloc")]
+pub use resume::SkippingResult;
 
 pub use capacity::CapacityError;
 pub use collision::Collision;
+pub use invalid::Invalid;
+pub use size_hint::SizeHintViolation;
+pub use sorted::SortedError;
 
+#[cfg(feature = "tuples")]
+pub use map::{MapPairs, MappedUnzipError};
 #[cfg(feature = "tuples")]
 pub use tuple_extend_error::*;
 #[cfg(feature = "tuples")]
@@ -41,10 +74,9 @@ pub mod types {
     pub use size_hinter::SizeHint;
 }
 
-/// Types related to [`PartialArray`](crate::impls::unsafe::PartialArray).
-#[cfg(feature = "unsafe")]
+/// Types related to [`PartialArray`](crate::impls::array::PartialArray).
 pub mod partial_array {
     #[doc(hidden)]
-    pub use crate::impls::r#unsafe::{ArrayIndex, PostInc};
-    pub use crate::impls::r#unsafe::{Drain, IntoArrayError, PartialArray};
+    pub use crate::impls::array::{ArrayIndex, PostInc};
+    pub use crate::impls::array::{Drain, IntoArrayError, Iter, IterMut, PartialArray};
 }
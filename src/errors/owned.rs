@@ -0,0 +1,89 @@
+use alloc::vec::{IntoIter, Vec};
+
+use crate::errors::{CollectError, CollectErrorData, ExtendError, ExtendErrorData};
+
+/// A [`CollectError`] whose remaining and collected items have been drained into
+/// [`Vec`]s, erasing the original iterator and collection types.
+///
+/// Unlike a [`CollectError`] over a borrowing or non-[`Send`] iterator, this type is
+/// `'static`, [`Send`] and [`Sync`] whenever `T` and `E` are, so it can be boxed into
+/// a `Box<dyn Error + Send + Sync>` or similar, and cross thread and API boundaries.
+///
+/// Created via [`CollectError::into_owned`].
+pub type OwnedCollectError<T, E> = CollectError<IntoIter<T>, Vec<T>, E>;
+
+/// An [`ExtendError`] whose remaining items have been drained into a [`Vec`],
+/// erasing the original iterator type.
+///
+/// Created via [`ExtendError::into_owned`].
+pub type OwnedExtendError<T, E> = ExtendError<IntoIter<T>, E>;
+
+impl<I: Iterator, C, E> CollectError<I, C, E>
+where
+    C: IntoIterator<Item = I::Item>,
+{
+    /// Consumes the error, draining the [`remain`](CollectError::remain) iterator and
+    /// the [`collected`](CollectError::collected) values into [`Vec`]s.
+    ///
+    /// The [`error`](CollectError::error) is kept as is, so it remains the
+    /// [`source`](core::error::Error::source) of the returned error, and still holds
+    /// any rejected item. All items remain recoverable via [`IntoIterator::into_iter`].
+    ///
+    /// Note that this consumes the remaining iterator, and so will not return if it
+    /// is infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use collect_failable::errors::collision::Collision;
+    /// # use std::collections::{BTreeSet, HashSet};
+    /// # use std::error::Error;
+    /// fn parse(data: &[i32]) -> Result<HashSet<i32>, Box<dyn Error + Send + Sync>> {
+    ///     // the error borrows `data`, so must be made owned before it can be boxed
+    ///     Ok(HashSet::try_from_iter(data.iter().copied()).map_err(|err| err.into_owned())?)
+    /// }
+    ///
+    /// let err = parse(&[1, 2, 1, 3]).expect_err("should collide");
+    /// assert!(err.source().is_some_and(|source| source.is::<Collision<i32>>()));
+    ///
+    /// let data = [1, 2, 1, 3];
+    /// let err = BTreeSet::try_from_iter(data.iter().copied()).expect_err("should collide").into_owned();
+    /// assert_eq!(err.collected, [1, 2]);
+    /// assert_eq!(err.into_iter().collect::<Vec<_>>(), [1, 1, 2, 3], "no items should be lost");
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> OwnedCollectError<I::Item, E> {
+        let CollectErrorData { remain, collected, error, position } = self.into_data();
+        let (remain, collected) = (remain.collect::<Vec<_>>().into_iter(), collected.into_iter().collect());
+        CollectError::from_data(CollectErrorData { remain, collected, error, position })
+    }
+}
+
+impl<I: Iterator, E> ExtendError<I, E> {
+    /// Consumes the error, draining the [`remain`](ExtendError::remain) iterator into a [`Vec`].
+    ///
+    /// The [`error`](ExtendError::error) is kept as is, so it remains the
+    /// [`source`](core::error::Error::source) of the returned error, and still holds
+    /// any rejected item.
+    ///
+    /// Note that this consumes the remaining iterator, and so will not return if it
+    /// is infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut set = HashSet::from([1]);
+    /// let err = set.try_extend([2, 1, 3]).expect_err("should collide").into_owned();
+    ///
+    /// assert_eq!(err.remain.as_slice(), [3]);
+    /// assert_eq!(err.error.item, 1);
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> OwnedExtendError<I::Item, E> {
+        let ExtendErrorData { remain, error, position } = self.into_data();
+        ExtendError::from_data(ExtendErrorData { remain: remain.collect::<Vec<_>>().into_iter(), error, position })
+    }
+}
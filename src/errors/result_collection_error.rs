@@ -3,6 +3,8 @@ use alloc::boxed::Box;
 
 use core::error::Error;
 use core::fmt::{Debug, Display, Formatter};
+use core::iter::{Chain, Flatten, Map};
+use core::option;
 
 use display_as_debug::fmt::DebugStructExt;
 use display_as_debug::types::{Full, Short};
@@ -36,6 +38,9 @@ pub struct ResultCollectError<E, C, CErr, I> {
     #[cfg(doc)]
     /// The remaining [`Iterator`] (items not yet consumed when the error occurred)
     pub iter: I,
+    #[cfg(doc)]
+    /// The zero-based index of the [`Err`] in the input, if known.
+    pub position: Option<usize>,
 
     #[cfg(all(not(doc), feature = "alloc"))]
     data: Box<ResultCollectErrorData<E, C, CErr, I>>,
@@ -52,6 +57,8 @@ pub struct ResultCollectErrorData<E, C, CErr, I> {
     pub result: Result<C, CErr>,
     /// The remaining [`Iterator`] (items not yet consumed when the error occurred)
     pub iter: I,
+    /// The zero-based index of the [`Err`] in the input, if known.
+    pub position: Option<usize>,
 }
 
 #[doc(hidden)]
@@ -60,14 +67,14 @@ impl<E, C, CErr, I> ResultCollectError<E, C, CErr, I> {
     #[must_use]
     #[cfg(feature = "alloc")]
     pub fn new(error: E, result: Result<C, CErr>, iter: I) -> Self {
-        ResultCollectErrorData { error, result, iter }.pipe(Box::new).pipe(|data| Self { data })
+        ResultCollectErrorData { error, result, iter, position: None }.pipe(Box::new).pipe(|data| Self { data })
     }
 
     /// Creates a new [`ResultCollectError`] from an iterator error and collection result.
     #[must_use]
     #[cfg(not(feature = "alloc"))]
     pub fn new(error: E, result: Result<C, CErr>, iter: I) -> Self {
-        ResultCollectErrorData { error, result, iter }.pipe(|data| Self { data })
+        ResultCollectErrorData { error, result, iter, position: None }.pipe(|data| Self { data })
     }
 }
 
@@ -89,6 +96,25 @@ impl<E, C, CErr, I> ResultCollectError<E, C, CErr, I> {
     pub fn into_data(self) -> ResultCollectErrorData<E, C, CErr, I> {
         self.data
     }
+
+    /// Sets the zero-based index of the [`Err`] in the input.
+    #[must_use]
+    pub const fn with_position(mut self, position: usize) -> Self {
+        self.data.position = Some(position);
+        self
+    }
+
+    /// Creates a new [`ResultCollectError`] from its data, preserving its position.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_data(data: ResultCollectErrorData<E, C, CErr, I>) -> Self {
+        data.pipe(Box::new).pipe(|data| Self { data })
+    }
+
+    /// Creates a new [`ResultCollectError`] from its data, preserving its position.
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) const fn from_data(data: ResultCollectErrorData<E, C, CErr, I>) -> Self {
+        Self { data }
+    }
 }
 
 #[doc(hidden)]
@@ -100,6 +126,75 @@ impl<E, C, CErr, I> core::ops::Deref for ResultCollectError<E, C, CErr, I> {
     }
 }
 
+/// Iterator over the items held by a [`ResultCollectError::result`], whether it is
+/// the partial collection or the collection error.
+type ResultItems<C, CErr> = Chain<Flatten<option::IntoIter<C>>, Flatten<option::IntoIter<CErr>>>;
+
+/// Iterator returned by [`ResultCollectError::into_iter`], which replays the original
+/// [`Result`] items.
+pub type ResultCollectIntoIter<E, C, CErr, I> = Chain<
+    Chain<
+        Map<ResultItems<C, CErr>, fn(<C as IntoIterator>::Item) -> Result<<C as IntoIterator>::Item, E>>,
+        option::IntoIter<Result<<C as IntoIterator>::Item, E>>,
+    >,
+    I,
+>;
+
+impl<E, C, CErr, I> IntoIterator for ResultCollectErrorData<E, C, CErr, I>
+where
+    C: IntoIterator,
+    CErr: IntoIterator<Item = C::Item>,
+    I: Iterator<Item = Result<C::Item, E>>,
+{
+    type Item = Result<C::Item, E>;
+    type IntoIter = ResultCollectIntoIter<E, C, CErr, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (collected, failed) = match self.result {
+            Ok(collected) => (Some(collected), None),
+            Err(failed) => (None, Some(failed)),
+        };
+
+        let items = collected.into_iter().flatten().chain(failed.into_iter().flatten());
+        items.map(Ok as fn(_) -> _).chain(Some(Err(self.error))).chain(self.iter)
+    }
+}
+
+/// Consumes the error and returns an [`Iterator`] that replays the original [`Result`] items.
+///
+/// The iterator yields an [`Ok`] for every item recovered from the
+/// [`result`](ResultCollectError::result), then the [`error`](ResultCollectError::error)
+/// as an [`Err`], followed by the remaining [`iter`](ResultCollectError::iter). If the
+/// collection failed, its error must also be an [`IntoIterator`], as all the errors of this
+/// crate are when their rejected item is recoverable.
+///
+/// This allows the input to be collected again with a different strategy, without losing
+/// any data. Note the order of the recovered items depends on the collection.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryFromIterator;
+/// # use std::collections::BTreeSet;
+/// let data = vec![Ok(1), Ok(2), Err("invalid"), Ok(3)];
+/// let err = Result::<BTreeSet<i32>, _>::try_from_iter(data.clone()).expect_err("should fail");
+///
+/// assert_eq!(err.into_iter().collect::<Vec<_>>(), data, "no items should be lost");
+/// ```
+impl<E, C, CErr, I> IntoIterator for ResultCollectError<E, C, CErr, I>
+where
+    C: IntoIterator,
+    CErr: IntoIterator<Item = C::Item>,
+    I: Iterator<Item = Result<C::Item, E>>,
+{
+    type Item = Result<C::Item, E>;
+    type IntoIter = ResultCollectIntoIter<E, C, CErr, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_data().into_iter()
+    }
+}
+
 #[doc(hidden)]
 #[allow(clippy::missing_fields_in_debug, reason = "All data is covered")]
 impl<E: Debug, C, CErr: Debug, I> Debug for ResultCollectErrorData<E, C, CErr, I> {
@@ -108,6 +203,7 @@ impl<E: Debug, C, CErr: Debug, I> Debug for ResultCollectErrorData<E, C, CErr, I
             .field("error", &self.error)
             .field("result", &TypeNameResult::borrow::<Short>(&self.result))
             .field_type::<I, Full>("iter")
+            .field("position", &self.position)
             .finish()
     }
 }
@@ -118,6 +214,7 @@ impl<E: Debug, C, CErr: Debug, I> Debug for ResultCollectError<E, C, CErr, I> {
             .field("error", &self.data.error)
             .field("result", &TypeNameResult::borrow::<Short>(&self.data.result))
             .field_type::<I, Full>("iter")
+            .field("position", &self.position)
             .finish()
     }
 }
@@ -0,0 +1,107 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::TryExtend;
+use crate::errors::{CollectError, CollectErrorData, ExtendError, ExtendErrorData};
+
+#[cfg(doc)]
+use crate::TryCollectEx;
+
+/// The result of [`TryCollectEx::try_collect_skipping`]: the position and error of every
+/// skipped item, along with the collected container, or the error that could not be skipped.
+#[cfg(feature = "alloc")]
+pub type SkippingResult<I, C, E> = (Vec<(usize, E)>, Result<C, CollectError<I, C, E>>);
+
+impl<I: Iterator, C, E> CollectError<I, C, E>
+where
+    C: TryExtend<I, Error = ExtendError<I, E>>,
+{
+    /// Consumes the error, skipping the rejected item, and resumes collection by extending the
+    /// [`collected`](CollectError::collected) values with the [`remain`](CollectError::remain)
+    /// iterator.
+    ///
+    /// Returns the skipped [`error`](CollectError::error), which holds any rejected item, along
+    /// with the result of resuming. The partially collected container is reused, rather than
+    /// rebuilt.
+    ///
+    /// The result is a new [`CollectError`] if extending fails, holding the container and the
+    /// items not yet consumed. Its [`position`](CollectError::position) is relative to the
+    /// original input, if both positions are known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use std::collections::BTreeSet;
+    /// let err = BTreeSet::try_from_iter([1, 2, 1, 3, 2, 4]).expect_err("should collide");
+    ///
+    /// let (skipped, result) = err.resume();
+    /// assert_eq!(skipped.item, 1);
+    ///
+    /// let err = result.expect_err("should collide again");
+    /// assert_eq!((err.error.item, err.position), (2, Some(4)));
+    ///
+    /// let (_, result) = err.resume();
+    /// assert_eq!(result.expect("should not collide"), BTreeSet::from([1, 2, 3, 4]));
+    /// ```
+    pub fn resume(self) -> (E, Result<C, Self>) {
+        let CollectErrorData { remain, mut collected, error: skipped, position } = self.into_data();
+
+        let result = match collected.try_extend(remain) {
+            Ok(()) => Ok(collected),
+            Err(err) => {
+                let ExtendErrorData { remain, error, position: next } = err.into_data();
+                let position = resumed_position(position, next);
+                Err(Self::from_data(CollectErrorData { remain, collected, error, position }))
+            }
+        };
+
+        (skipped, result)
+    }
+}
+
+impl<I: Iterator, E> ExtendError<I, E> {
+    /// Consumes the error, skipping the rejected item, and resumes extending `collection` with
+    /// the [`remain`](ExtendError::remain) iterator.
+    ///
+    /// Returns the skipped [`error`](ExtendError::error), which holds any rejected item, along
+    /// with the result of resuming. `collection` should be the collection that produced this
+    /// error.
+    ///
+    /// The result is a new [`ExtendError`] if extending fails, holding the items not yet
+    /// consumed. Its [`position`](ExtendError::position) is relative to the original input, if
+    /// both positions are known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut set = HashSet::from([1]);
+    /// let err = set.try_extend([2, 1, 3]).expect_err("should collide");
+    ///
+    /// let (skipped, result) = err.resume_into(&mut set);
+    /// assert_eq!(skipped.item, 1);
+    /// result.expect("should not collide");
+    /// assert_eq!(set, HashSet::from([1, 2, 3]));
+    /// ```
+    pub fn resume_into<C>(self, collection: &mut C) -> (E, Result<(), Self>)
+    where
+        C: TryExtend<I, Error = Self>,
+    {
+        let ExtendErrorData { remain, error: skipped, position } = self.into_data();
+
+        let result = collection.try_extend(remain).map_err(|err| {
+            let ExtendErrorData { remain, error, position: next } = err.into_data();
+            Self::from_data(ExtendErrorData { remain, error, position: resumed_position(position, next) })
+        });
+
+        (skipped, result)
+    }
+}
+
+/// Combines the position of a skipped item with the position of a later error, which is
+/// relative to the item after the skipped one.
+fn resumed_position(skipped: Option<usize>, next: Option<usize>) -> Option<usize> {
+    skipped.zip(next).map(|(skipped, next)| skipped + 1 + next)
+}
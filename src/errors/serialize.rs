@@ -0,0 +1,70 @@
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use size_hinter::SizeHint;
+
+use crate::errors::{CollectError, ExtendError};
+
+/// Serializes a [`SizeHint`] as a struct with `lower` and `upper` bounds,
+/// where an unbounded `upper` is serialized as `None`.
+#[allow(clippy::trivially_copy_pass_by_ref, reason = "signature required by serialize_with")]
+pub fn size_hint<S: Serializer>(hint: &SizeHint, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("SizeHint", 2)?;
+    state.serialize_field("lower", &hint.lower())?;
+    state.serialize_field("upper", &hint.upper())?;
+    state.end()
+}
+
+/// Serializes a summary of the error, consisting of the [`error`](CollectError::error),
+/// the number of [`collected`](CollectError::collected) items and the
+/// [`position`](CollectError::position), if known.
+///
+/// The [`remain`](CollectError::remain) iterator is not serialized, so need not be
+/// [`Serialize`], and is not consumed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryFromIterator;
+/// # use std::collections::BTreeSet;
+/// let err = BTreeSet::try_from_iter([1, 2, 1, 3]).expect_err("should collide");
+/// let json = serde_json::to_string(&err).expect("should serialize");
+///
+/// assert_eq!(json, r#"{"error":{"item":1},"collected":2,"position":2}"#);
+/// ```
+impl<I, C, E: Serialize> Serialize for CollectError<I, C, E>
+where
+    for<'a> &'a C: IntoIterator,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("CollectError", 3)?;
+        state.serialize_field("error", &self.error)?;
+        state.serialize_field("collected", &(&self.collected).into_iter().count())?;
+        state.serialize_field("position", &self.position)?;
+        state.end()
+    }
+}
+
+/// Serializes a summary of the error, consisting of the [`error`](ExtendError::error)
+/// and the [`position`](ExtendError::position), if known.
+///
+/// The [`remain`](ExtendError::remain) iterator is not serialized, so need not be
+/// [`Serialize`], and is not consumed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryExtend;
+/// # use std::collections::HashSet;
+/// let mut set = HashSet::from([1]);
+/// let err = set.try_extend([2, 1]).expect_err("should collide");
+/// let json = serde_json::to_string(&err).expect("should serialize");
+///
+/// assert_eq!(json, r#"{"error":{"item":1},"position":1}"#);
+/// ```
+impl<I, E: Serialize> Serialize for ExtendError<I, E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ExtendError", 2)?;
+        state.serialize_field("error", &self.error)?;
+        state.serialize_field("position", &self.position)?;
+        state.end()
+    }
+}
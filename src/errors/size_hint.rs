@@ -0,0 +1,119 @@
+use crate::errors::types::SizeHint;
+
+#[cfg(doc)]
+use crate::iter::CheckedSizeHint;
+
+/// An error indicating that an [`Iterator`] reported an invalid
+/// [`size_hint`](Iterator::size_hint), or produced a number of items outside of it.
+///
+/// This error represents three failure modes, identified by [`SizeHintViolation::kind`].
+///
+/// - [`SizeHintViolationKind::Invalid`] — the lower bound of the hint exceeds its upper bound.
+/// - [`SizeHintViolationKind::Overflow`] — the iterator produced more items than its upper bound.
+/// - [`SizeHintViolationKind::Underflow`] — the iterator ended before producing its lower bound.
+///
+/// Violations are detected by [`CheckedSizeHint`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::errors::size_hint::{SizeHintViolation, SizeHintViolationKind};
+/// let error = SizeHintViolation::validate((5, Some(2))).expect_err("should be invalid");
+///
+/// assert_eq!(error.hint, (5, Some(2)));
+/// assert_eq!(error.kind, SizeHintViolationKind::Invalid);
+/// ```
+#[subdef::subdef]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[error("Iterator violated its size hint {hint:?}: {kind}")]
+pub struct SizeHintViolation {
+    /// The [`size_hint`](Iterator::size_hint) reported by the iterator before iteration.
+    pub hint: (usize, Option<usize>),
+    /// The specific kind of violation that occurred.
+    pub kind: [SizeHintViolationKind; {
+        /// Describes how an iterator violated its [`size_hint`](Iterator::size_hint).
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+        pub enum SizeHintViolationKind {
+            /// The lower bound of the hint exceeds its upper bound.
+            #[display("lower bound exceeds upper bound")]
+            Invalid,
+            /// The iterator produced more items than the upper bound of its hint.
+            #[display("produced {count} items, more than the upper bound")]
+            Overflow {
+                /// The number of items produced when the violation was detected.
+                count: usize,
+            },
+            /// The iterator ended before producing the lower bound of its hint.
+            #[display("produced {count} items, fewer than the lower bound")]
+            Underflow {
+                /// The number of items produced.
+                count: usize,
+            },
+        }
+    }],
+}
+
+impl SizeHintViolation {
+    /// Validates `hint`, returning it as a [`SizeHint`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Invalid`](SizeHintViolationKind::Invalid) [`SizeHintViolation`]
+    /// if the lower bound of `hint` exceeds its upper bound.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::size_hint::SizeHintViolation;
+    /// # use collect_failable::errors::types::SizeHint;
+    /// assert_eq!(SizeHintViolation::validate((1, Some(3))), Ok(SizeHint::bounded(1, 3)));
+    /// SizeHintViolation::validate((3, Some(1))).expect_err("should be invalid");
+    /// ```
+    pub fn validate(hint: (usize, Option<usize>)) -> Result<SizeHint, Self> {
+        SizeHint::try_from(hint).map_err(|_| Self { hint, kind: SizeHintViolationKind::Invalid })
+    }
+
+    /// Creates a new [`Overflow`](SizeHintViolationKind::Overflow) [`SizeHintViolation`]
+    /// indicating that an iterator produced `count` items, more than the upper bound of `hint`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hint` has no upper bound, or `count` does not exceed it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::size_hint::{SizeHintViolation, SizeHintViolationKind};
+    /// let error = SizeHintViolation::overflow((0, Some(2)), 3);
+    ///
+    /// assert_eq!(error.kind, SizeHintViolationKind::Overflow { count: 3 });
+    /// ```
+    #[must_use]
+    pub fn overflow(hint: (usize, Option<usize>), count: usize) -> Self {
+        assert!(hint.1.is_some_and(|upper| count > upper), "count must exceed the upper bound");
+        Self { hint, kind: SizeHintViolationKind::Overflow { count } }
+    }
+
+    /// Creates a new [`Underflow`](SizeHintViolationKind::Underflow) [`SizeHintViolation`]
+    /// indicating that an iterator ended after `count` items, fewer than the lower bound of `hint`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is not less than the lower bound of `hint`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::size_hint::{SizeHintViolation, SizeHintViolationKind};
+    /// let error = SizeHintViolation::underflow((3, None), 1);
+    ///
+    /// assert_eq!(error.kind, SizeHintViolationKind::Underflow { count: 1 });
+    /// ```
+    #[must_use]
+    pub fn underflow(hint: (usize, Option<usize>), count: usize) -> Self {
+        assert!(count < hint.0, "count must be less than the lower bound");
+        Self { hint, kind: SizeHintViolationKind::Underflow { count } }
+    }
+}
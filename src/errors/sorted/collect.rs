@@ -0,0 +1,59 @@
+use crate::errors::CollectError;
+use crate::errors::sorted::error::SortedError;
+
+/// Specialization of [`CollectError`] for [`SortedError`].
+///
+/// This type is used when collection from sorted input fails, because an item
+/// collided with, or was out of order relative to, the previous item.
+///
+/// # Type Parameters
+///
+/// - `I`: The type of the [Iterator] that was used to iterate the values.
+/// - `C`: The type of the collection that was used to collect the values.
+///
+/// # Data Recovery
+///
+/// If `C` implements [`IntoIterator`], this type implements [`IntoIterator`]
+/// as well, allowing the data in the original iterator to be reconstructed from
+/// [`CollectError::remain`], [`CollectError::collected`], and the rejected item.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::errors::CollectError;
+/// # use std::collections::BTreeSet;
+/// let error = CollectError::<_, BTreeSet<_>, _>::not_sorted(4..=4, BTreeSet::from([1, 3]), 2);
+///
+/// let values = error.into_iter().collect::<Vec<_>>();
+///
+/// assert_eq!(values, vec![2, 1, 3, 4]);
+/// ```
+impl<I: Iterator, C> CollectError<I, C, SortedError<I::Item>> {
+    /// Creates a new [`CollectError`] with a [`NotSorted`](crate::errors::sorted::SortedErrorKind::NotSorted)
+    /// [`SortedError`], for collection failures due to out of order input.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - The remaining [Iterator] after the out of order item
+    /// * `collected` - The values that were collected before the out of order item
+    /// * `item` - The item that was out of order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::CollectError;
+    /// # use collect_failable::errors::sorted::SortedErrorKind;
+    /// # use std::collections::BTreeSet;
+    /// let error = CollectError::<_, BTreeSet<_>, _>::not_sorted(4..=4, BTreeSet::from([1, 3]), 2);
+    ///
+    /// assert_eq!(error.remain, 4..=4);
+    /// assert_eq!(error.collected, BTreeSet::from([1, 3]));
+    /// assert_eq!(error.error.item, 2);
+    /// assert_eq!(error.error.kind, SortedErrorKind::NotSorted);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn not_sorted(iter: I, collected: C, item: I::Item) -> Self {
+        Self::new(iter, collected, SortedError::not_sorted(item))
+    }
+}
@@ -0,0 +1,111 @@
+use crate::errors::{ErrorItemProvider, MapErrorItem};
+use crate::errors::collision::Collision;
+
+/// Error type for when an item cannot be added to a collection built from
+/// sorted input, because it collides with, or is out of order relative to,
+/// the previous item.
+///
+/// The failure mode is identified by [`SortedError::kind`].
+///
+/// - [`SortedErrorKind::Collision`] — the item is equal to the previous item,
+///   or to an item already in the collection.
+/// - [`SortedErrorKind::NotSorted`] — the item is less than the previous item.
+///
+/// In both cases the rejected item is preserved, and may be recovered via
+/// [`ErrorItemProvider`].
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the item that was rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::errors::sorted::{SortedError, SortedErrorKind};
+/// let error = SortedError::not_sorted(1);
+///
+/// assert_eq!(error.item, 1);
+/// assert_eq!(error.kind, SortedErrorKind::NotSorted);
+/// ```
+#[subdef::subdef]
+#[derive(Debug, PartialEq, Eq, thiserror::Error, derive_more::Constructor)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[error("Sorted collection failed: {kind}")]
+pub struct SortedError<T> {
+    /// The item that was rejected.
+    pub item: T,
+    /// The reason the item was rejected.
+    pub kind: [SortedErrorKind; {
+        /// Describes why an item was rejected from sorted input.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+        pub enum SortedErrorKind {
+            /// The item is equal to the previous item, or an item already in the collection.
+            #[display("item collision")]
+            Collision,
+            /// The item is less than the previous item.
+            #[display("item out of order")]
+            NotSorted,
+        }
+    }],
+}
+
+impl<T> SortedError<T> {
+    /// Creates a new [`Collision`](SortedErrorKind::Collision) [`SortedError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::sorted::{SortedError, SortedErrorKind};
+    /// let error = SortedError::collision(1);
+    ///
+    /// assert_eq!(error.item, 1);
+    /// assert_eq!(error.kind, SortedErrorKind::Collision);
+    /// ```
+    #[must_use]
+    pub const fn collision(item: T) -> Self {
+        Self::new(item, SortedErrorKind::Collision)
+    }
+
+    /// Creates a new [`NotSorted`](SortedErrorKind::NotSorted) [`SortedError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::sorted::{SortedError, SortedErrorKind};
+    /// let error = SortedError::not_sorted(1);
+    ///
+    /// assert_eq!(error.item, 1);
+    /// assert_eq!(error.kind, SortedErrorKind::NotSorted);
+    /// ```
+    #[must_use]
+    pub const fn not_sorted(item: T) -> Self {
+        Self::new(item, SortedErrorKind::NotSorted)
+    }
+}
+
+impl<T> From<Collision<T>> for SortedError<T> {
+    fn from(collision: Collision<T>) -> Self {
+        Self::collision(collision.item)
+    }
+}
+
+impl<T> ErrorItemProvider for SortedError<T> {
+    type Item = T;
+
+    fn into_item(self) -> Option<Self::Item> {
+        Some(self.item)
+    }
+
+    fn item(&self) -> Option<&Self::Item> {
+        Some(&self.item)
+    }
+}
+
+impl<T> MapErrorItem for SortedError<T> {
+    type Mapped<U> = SortedError<U>;
+
+    fn map_item<U, F: FnOnce(T) -> U>(self, f: F) -> SortedError<U> {
+        SortedError::new(f(self.item), self.kind)
+    }
+}
@@ -0,0 +1,4 @@
+mod collect;
+mod error;
+
+pub use error::*;
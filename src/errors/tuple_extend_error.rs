@@ -3,6 +3,7 @@ use alloc::boxed::Box;
 
 use core::error::Error;
 use core::fmt::{Debug, Display, Formatter};
+use core::iter::Chain;
 use core::ops::Deref;
 
 use display_as_debug::fmt::DebugStructExt;
@@ -10,6 +11,8 @@ use display_as_debug::types::{Full, Short};
 use nameof::name_of;
 use tap::Pipe;
 
+use crate::errors::ErrorItemProvider;
+
 /// An error that occurs when extending a tuple of collections fails.
 ///
 /// This error preserves the error from the side that failed, any pending item from the other side,
@@ -35,6 +38,9 @@ pub struct TupleExtendError<E, P, I> {
     #[cfg(doc)]
     /// The remaining iterator after the error occurred
     pub remaining: I,
+    #[cfg(doc)]
+    /// The zero-based index of the pair in the input that caused the error, if known.
+    pub position: Option<usize>,
 
     #[cfg(all(not(doc), feature = "alloc"))]
     data: Box<TupleExtendErrorData<E, P, I>>,
@@ -51,6 +57,8 @@ pub struct TupleExtendErrorData<E, P, I> {
     pub pending: Option<P>,
     /// The remaining iterator after the error occurred
     pub remaining: I,
+    /// The zero-based index of the pair in the input that caused the error, if known.
+    pub position: Option<usize>,
 }
 
 #[doc(hidden)]
@@ -59,14 +67,14 @@ impl<E, P, I> TupleExtendError<E, P, I> {
     #[must_use]
     #[cfg(feature = "alloc")]
     pub fn new(error: E, pending: Option<P>, remaining: I) -> Self {
-        TupleExtendErrorData { error, pending, remaining }.pipe(Box::new).pipe(|data| Self { data })
+        TupleExtendErrorData { error, pending, remaining, position: None }.pipe(Box::new).pipe(|data| Self { data })
     }
 
     /// Creates a new [`TupleExtendError`].
     #[must_use]
     #[cfg(not(feature = "alloc"))]
     pub fn new(error: E, pending: Option<P>, remaining: I) -> Self {
-        TupleExtendErrorData { error, pending, remaining }.pipe(|data| Self { data })
+        TupleExtendErrorData { error, pending, remaining, position: None }.pipe(|data| Self { data })
     }
 }
 
@@ -84,6 +92,87 @@ impl<E, P, I> TupleExtendError<E, P, I> {
     pub fn into_data(self) -> TupleExtendErrorData<E, P, I> {
         self.data
     }
+
+    /// Sets the zero-based index of the pair in the input that caused the error.
+    #[must_use]
+    pub const fn with_position(mut self, position: usize) -> Self {
+        self.data.position = Some(position);
+        self
+    }
+
+    /// Creates a new [`TupleExtendError`] from its data, preserving its position.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_data(data: TupleExtendErrorData<E, P, I>) -> Self {
+        data.pipe(Box::new).pipe(|data| Self { data })
+    }
+
+    /// Creates a new [`TupleExtendError`] from its data, preserving its position.
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) const fn from_data(data: TupleExtendErrorData<E, P, I>) -> Self {
+        Self { data }
+    }
+}
+
+/// Recovery iterator returned by [`TupleExtendError::into_left_iter`].
+pub type TupleExtendLeftIter<T, P, I> = Chain<core::option::IntoIter<(T, P)>, I>;
+
+impl<E: ErrorItemProvider, P, I> TupleExtendError<E, P, I> {
+    /// Consumes an error from the [`Left`](either::Either::Left) side of a tuple extension
+    /// failure, where the first collection failed, and returns an iterator over the pairs
+    /// that were not added to the collections.
+    ///
+    /// The rejected item is paired back up with the [`pending`](TupleExtendError::pending)
+    /// item, followed by the [`remaining`](TupleExtendError::remaining) iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use collect_failable::errors::TupleExtendError;
+    /// # use std::collections::HashSet;
+    /// let mut sets = (HashSet::from([1]), HashSet::from(['a']));
+    /// let err = sets.try_extend([(2, 'b'), (1, 'c'), (3, 'd')]).expect_err("should collide");
+    ///
+    /// let recovered = err.left().expect("should collide on left side").into_left_iter();
+    /// assert_eq!(recovered.collect::<Vec<_>>(), [(1, 'c'), (3, 'd')]);
+    /// ```
+    pub fn into_left_iter(self) -> TupleExtendLeftIter<E::Item, P, I>
+    where
+        I: Iterator<Item = (E::Item, P)>,
+    {
+        let TupleExtendErrorData { error, pending, remaining, .. } = self.into_data();
+        error.into_item().zip(pending).into_iter().chain(remaining)
+    }
+
+    /// Consumes an error from the [`Right`](either::Either::Right) side of a tuple extension
+    /// failure, where the second collection failed, and returns the rejected item and the
+    /// [`remaining`](TupleExtendError::remaining) iterator as separate parts.
+    ///
+    /// The first item of the failed pair has already been added to the first collection, so the
+    /// pair cannot be rebuilt. The rejected second item is returned on its own instead, so that
+    /// no item is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use std::collections::HashSet;
+    /// let mut sets = (HashSet::from([1]), HashSet::from(['a']));
+    /// let err = sets.try_extend([(2, 'b'), (3, 'a'), (4, 'd')]).expect_err("should collide");
+    ///
+    /// let (rejected, remaining) = err.right().expect("should collide on right side").into_right_parts();
+    /// assert_eq!(rejected, Some('a'));
+    /// assert_eq!(remaining.collect::<Vec<_>>(), [(4, 'd')]);
+    /// assert!(sets.0.contains(&3), "the first item of the failed pair was already added");
+    /// ```
+    #[must_use]
+    pub fn into_right_parts(self) -> (Option<E::Item>, I)
+    where
+        I: Iterator<Item = (P, E::Item)>,
+    {
+        let TupleExtendErrorData { error, remaining, .. } = self.into_data();
+        (error.into_item(), remaining)
+    }
 }
 
 #[doc(hidden)]
@@ -101,6 +190,7 @@ impl<E: Debug, P, I> Debug for TupleExtendError<E, P, I> {
             .field(name_of!(error in Self), &self.error)
             .field_type::<P, Short>(name_of!(pending in Self))
             .field_type::<I, Full>(name_of!(remaining in Self))
+            .field(name_of!(position in Self), &self.position)
             .finish()
     }
 }
@@ -113,6 +203,7 @@ impl<E: Debug, P, I> Debug for TupleExtendErrorData<E, P, I> {
             .field(name_of!(error in Self), &self.error)
             .field_type::<P, Short>(name_of!(pending in Self))
             .field_type::<I, Full>(name_of!(remaining in Self))
+            .field(name_of!(position in Self), &self.position)
             .finish()
     }
 }
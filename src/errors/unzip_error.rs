@@ -3,6 +3,7 @@ use alloc::boxed::Box;
 
 use core::error::Error;
 use core::fmt::{Debug, Display, Formatter};
+use core::iter::{Chain, Map, Zip};
 use core::ops::Deref;
 
 use display_as_debug::fmt::DebugStructExt;
@@ -11,7 +12,11 @@ use display_as_debug::wrap::TypeNameOption;
 use nameof::name_of;
 use tap::Pipe;
 
-use crate::TryExtendOne;
+use crate::errors::ErrorItemProvider;
+use crate::{InsertionOrder, TryExtendOne};
+
+#[cfg(doc)]
+use crate::TryUnzip;
 
 /// An error that occurs when unzipping an iterator into two collections fails.
 ///
@@ -46,6 +51,9 @@ pub struct UnzipError<E, Failed, Partial, Pending, I> {
     #[cfg(doc)]
     /// The remaining iterator after the error occurred
     pub remaining: I,
+    #[cfg(doc)]
+    /// The zero-based index of the pair in the input that caused the error, if known.
+    pub position: Option<usize>,
 
     #[cfg(all(not(doc), feature = "alloc"))]
     data: Box<UnzipErrorData<E, Failed, Partial, Pending, I>>,
@@ -66,6 +74,8 @@ pub struct UnzipErrorData<E, Failed, Partial, Pending, I> {
     pub pending: Option<Pending>,
     /// The remaining iterator after the error occurred
     pub remaining: I,
+    /// The zero-based index of the pair in the input that caused the error, if known.
+    pub position: Option<usize>,
 }
 
 #[doc(hidden)]
@@ -74,18 +84,32 @@ impl<Failed: TryExtendOne, Partial: TryExtendOne, I: Iterator> UnzipError<Failed
     #[must_use]
     #[cfg(feature = "alloc")]
     pub fn new(error: Failed::Error, failed: Failed, partial: Partial, pending: Option<Partial::Item>, remaining: I) -> Self {
-        UnzipErrorData { error, failed, partial, pending, remaining }.pipe(Box::new).pipe(|data| Self { data })
+        UnzipErrorData { error, failed, partial, pending, remaining, position: None }.pipe(Box::new).pipe(|data| Self { data })
     }
 
     /// Creates a new [`UnzipError`].
     #[must_use]
     #[cfg(not(feature = "alloc"))]
     pub fn new(error: Failed::Error, failed: Failed, partial: Partial, pending: Option<Partial::Item>, remaining: I) -> Self {
-        UnzipErrorData { error, failed, partial, pending, remaining }.pipe(|data| Self { data })
+        UnzipErrorData { error, failed, partial, pending, remaining, position: None }.pipe(|data| Self { data })
     }
 }
 
 impl<E, Failed, Partial, Pending, I> UnzipError<E, Failed, Partial, Pending, I> {
+    /// Creates a new [`UnzipError`] from its data, without requiring the collections
+    /// to implement [`TryExtendOne`].
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_data(data: UnzipErrorData<E, Failed, Partial, Pending, I>) -> Self {
+        data.pipe(Box::new).pipe(|data| Self { data })
+    }
+
+    /// Creates a new [`UnzipError`] from its data, without requiring the collections
+    /// to implement [`TryExtendOne`].
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) const fn from_data(data: UnzipErrorData<E, Failed, Partial, Pending, I>) -> Self {
+        Self { data }
+    }
+
     /// Consumes the error, returning the data.
     #[must_use]
     #[cfg(feature = "alloc")]
@@ -99,6 +123,114 @@ impl<E, Failed, Partial, Pending, I> UnzipError<E, Failed, Partial, Pending, I>
     pub fn into_data(self) -> UnzipErrorData<E, Failed, Partial, Pending, I> {
         self.data
     }
+
+    /// Sets the zero-based index of the pair in the input that caused the error.
+    #[must_use]
+    pub const fn with_position(mut self, position: usize) -> Self {
+        self.data.position = Some(position);
+        self
+    }
+}
+
+/// Iterator over the items of one side of an [`UnzipError`], followed by the item that
+/// was being added to it when the error occurred.
+type SideIter<C> = Chain<<C as IntoIterator>::IntoIter, core::option::IntoIter<<C as IntoIterator>::Item>>;
+
+/// Iterator over the pairs recovered from the collections of an [`UnzipError`], in
+/// `(failed, partial)` order.
+type RecoveredPairs<Failed, Partial> = Zip<SideIter<Failed>, SideIter<Partial>>;
+
+/// Recovery iterator returned by [`UnzipError::into_left_iter`].
+pub type UnzipLeftIter<Failed, Partial, I> = Chain<RecoveredPairs<Failed, Partial>, I>;
+
+/// Recovery iterator returned by [`UnzipError::into_right_iter`].
+pub type UnzipRightIter<Failed, Partial, I> = Chain<
+    Map<
+        RecoveredPairs<Failed, Partial>,
+        fn((<Failed as IntoIterator>::Item, <Partial as IntoIterator>::Item)) -> (<Partial as IntoIterator>::Item, <Failed as IntoIterator>::Item),
+    >,
+    I,
+>;
+
+impl<E, Failed, Partial, I> UnzipError<E, Failed, Partial, Partial::Item, I>
+where
+    E: ErrorItemProvider<Item = Failed::Item>,
+    Failed: IntoIterator,
+    Partial: IntoIterator,
+{
+    /// Zips the collections back into pairs, followed by the pair that failed, in
+    /// `(failed, partial)` order.
+    fn into_recovered_pairs(self) -> (RecoveredPairs<Failed, Partial>, I) {
+        let UnzipErrorData { error, failed, partial, pending, remaining, .. } = self.into_data();
+        let failed = failed.into_iter().chain(error.into_item());
+        let partial = partial.into_iter().chain(pending);
+        (failed.zip(partial), remaining)
+    }
+
+    /// Consumes an error from the [`Left`](either::Either::Left) side of a [`TryUnzip`]
+    /// failure, where the first collection failed, and returns an iterator over all the
+    /// pairs of the original iterator.
+    ///
+    /// The collections are re-zipped with the rejected item and the pending item,
+    /// followed by the remaining iterator. Both collections must iterate in insertion
+    /// order, as marked by [`InsertionOrder`], so the pairs are reproduced in their original
+    /// order, and with their original partners.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryUnzip;
+    /// # use indexmap::IndexSet;
+    /// let pairs = vec![(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd')];
+    /// let err = pairs.clone().into_iter().try_unzip::<IndexSet<_>, Vec<_>>().expect_err("should collide");
+    ///
+    /// let recovered = err.expect_left("only the set can collide").into_left_iter();
+    /// assert_eq!(recovered.collect::<Vec<_>>(), pairs, "no pairs should be lost");
+    /// ```
+    pub fn into_left_iter(self) -> UnzipLeftIter<Failed, Partial, I>
+    where
+        Failed: InsertionOrder,
+        Partial: InsertionOrder,
+        I: Iterator<Item = (Failed::Item, Partial::Item)>,
+    {
+        let (pairs, remaining) = self.into_recovered_pairs();
+        pairs.chain(remaining)
+    }
+
+    /// Consumes an error from the [`Right`](either::Either::Right) side of a [`TryUnzip`]
+    /// failure, where the second collection failed, and returns an iterator over all the
+    /// pairs of the original iterator.
+    ///
+    /// This is the mirror of [`UnzipError::into_left_iter`], and is subject to the same
+    /// ordering requirements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryUnzip;
+    /// # use collect_failable::errors::UnzipError;
+    /// # use indexmap::IndexSet;
+    /// let pairs = vec![(1, 'a'), (2, 'b'), (3, 'a'), (4, 'd')];
+    /// let err = pairs.clone().into_iter().try_unzip::<IndexSet<_>, IndexSet<_>>().expect_err("should collide");
+    ///
+    /// // either side may fail, so handle both
+    /// let recovered = err.map_either(UnzipError::into_left_iter, UnzipError::into_right_iter);
+    /// assert_eq!(recovered.collect::<Vec<_>>(), pairs, "no pairs should be lost");
+    /// ```
+    pub fn into_right_iter(self) -> UnzipRightIter<Failed, Partial, I>
+    where
+        Failed: InsertionOrder,
+        Partial: InsertionOrder,
+        I: Iterator<Item = (Partial::Item, Failed::Item)>,
+    {
+        let (pairs, remaining) = self.into_recovered_pairs();
+        pairs.map(swap as fn(_) -> _).chain(remaining)
+    }
+}
+
+/// Swaps the elements of a pair.
+fn swap<A, B>((a, b): (A, B)) -> (B, A) {
+    (b, a)
 }
 
 #[doc(hidden)]
@@ -120,6 +252,7 @@ impl<E: Debug, Failed, Partial, Pending, I> Debug for UnzipErrorData<E, Failed,
             .field_type::<Partial, Short>(name_of!(partial in Self))
             .field(name_of!(pending in Self), &TypeNameOption::borrow::<Short>(&self.pending))
             .field_type::<I, Full>(name_of!(remaining in Self))
+            .field(name_of!(position in Self), &self.position)
             .finish()
     }
 }
@@ -132,6 +265,7 @@ impl<E: Debug, Failed, Partial, Pending, I> Debug for UnzipError<E, Failed, Part
             .field_type::<Partial, Short>(name_of!(partial in Self))
             .field(name_of!(pending in Self), &TypeNameOption::borrow::<Short>(&self.pending))
             .field_type::<I, Full>(name_of!(remaining in Self))
+            .field(name_of!(position in Self), &self.position)
             .finish()
     }
 }
@@ -0,0 +1,132 @@
+use core::convert::Infallible;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use crate::errors::{ErrorItemProvider, MapErrorItem};
+
+/// Error type for collections that wrap an inner collection.
+///
+/// Used by wrappers such as [`MinLen`](crate::collections::MinLen) and
+/// [`Validated`](crate::collections::Validated), where an item may be rejected either by the
+/// wrapper's own invariant, or by the inner collection.
+///
+/// # Type Parameters
+///
+/// - `E`: The error from the wrapper's own invariant.
+/// - `Inner`: The error from the inner collection.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryFromIterator;
+/// # use collect_failable::collections::NonEmpty;
+/// # use collect_failable::errors::{Collision, WrapperError};
+/// # use std::collections::HashSet;
+/// let err = NonEmpty::<HashSet<i32>>::try_from_iter([1, 2, 1]).expect_err("should collide");
+/// assert_eq!(err.error, WrapperError::Inner(Collision::new(1)));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub enum WrapperError<E, Inner> {
+    /// The item violated the wrapper's own invariant.
+    Wrapper(E),
+    /// The item was rejected by the inner collection.
+    Inner(Inner),
+}
+
+impl<E> WrapperError<E, Infallible> {
+    /// Consumes the error, returning the wrapper's error, as an inner collection that
+    /// can not reject an item can not produce an [`Inner`](WrapperError::Inner) error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryFromIterator;
+    /// # use collect_failable::collections::NonEmpty;
+    /// # use collect_failable::errors::capacity::CapacityErrorKind;
+    /// let err = NonEmpty::<Vec<i32>>::try_from_iter((1..=3).filter(|_| false)).expect_err("should be empty");
+    /// assert_eq!(err.into_data().error.into_wrapper().kind, CapacityErrorKind::Underflow { count: 0 });
+    /// ```
+    pub fn into_wrapper(self) -> E {
+        match self {
+            Self::Wrapper(error) => error,
+            Self::Inner(never) => match never {},
+        }
+    }
+}
+
+impl<E: Display, Inner: Display> Display for WrapperError<E, Inner> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Wrapper(error) => error.fmt(f),
+            Self::Inner(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<E: Error, Inner: Error> Error for WrapperError<E, Inner> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Wrapper(error) => error.source(),
+            Self::Inner(error) => error.source(),
+        }
+    }
+}
+
+impl<E: ErrorItemProvider, Inner: ErrorItemProvider<Item = E::Item>> ErrorItemProvider for WrapperError<E, Inner> {
+    type Item = E::Item;
+
+    fn into_item(self) -> Option<Self::Item> {
+        match self {
+            Self::Wrapper(error) => error.into_item(),
+            Self::Inner(error) => error.into_item(),
+        }
+    }
+
+    fn item(&self) -> Option<&Self::Item> {
+        match self {
+            Self::Wrapper(error) => error.item(),
+            Self::Inner(error) => error.item(),
+        }
+    }
+}
+
+/// For inner collections that can not reject an item, such as [`Vec`](alloc::vec::Vec).
+impl<E: ErrorItemProvider> ErrorItemProvider for WrapperError<E, Infallible> {
+    type Item = E::Item;
+
+    fn into_item(self) -> Option<Self::Item> {
+        match self {
+            Self::Wrapper(error) => error.into_item(),
+            Self::Inner(never) => match never {},
+        }
+    }
+
+    fn item(&self) -> Option<&Self::Item> {
+        match self {
+            Self::Wrapper(error) => error.item(),
+            Self::Inner(_) => None,
+        }
+    }
+}
+
+impl<E: MapErrorItem, Inner: MapErrorItem<Item = E::Item>> MapErrorItem for WrapperError<E, Inner> {
+    type Mapped<U> = WrapperError<E::Mapped<U>, Inner::Mapped<U>>;
+
+    fn map_item<U, F: FnOnce(E::Item) -> U>(self, f: F) -> Self::Mapped<U> {
+        match self {
+            Self::Wrapper(error) => WrapperError::Wrapper(error.map_item(f)),
+            Self::Inner(error) => WrapperError::Inner(error.map_item(f)),
+        }
+    }
+}
+
+impl<E: MapErrorItem> MapErrorItem for WrapperError<E, Infallible> {
+    type Mapped<U> = WrapperError<E::Mapped<U>, Infallible>;
+
+    fn map_item<U, F: FnOnce(E::Item) -> U>(self, f: F) -> Self::Mapped<U> {
+        match self {
+            Self::Wrapper(error) => WrapperError::Wrapper(error.map_item(f)),
+            Self::Inner(never) => match never {},
+        }
+    }
+}
@@ -27,6 +27,16 @@ impl<const N: usize> ArrayIndex<N> {
     pub fn try_post_inc(&mut self) -> Option<usize> {
         (self.0 < N).then(|| self.0.post_inc())
     }
+
+    /// Decrements the internal counter, then returns the new index.
+    ///
+    /// If the index is already at 0, returns `None`.
+    pub fn try_pre_dec(&mut self) -> Option<usize> {
+        (self.0 > 0).then(|| {
+            self.0 -= 1;
+            self.0
+        })
+    }
 }
 
 /// A trait for types that can be post-incremented.
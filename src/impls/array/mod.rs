@@ -0,0 +1,23 @@
+mod try_from_iter;
+mod array_index;
+mod partial_array;
+
+#[cfg(feature = "unsafe")]
+mod r#unsafe;
+
+#[cfg(not(feature = "unsafe"))]
+mod safe;
+
+#[doc(hidden)]
+pub use array_index::{ArrayIndex, PostInc};
+pub use partial_array::{IntoArrayError, Iter, IterMut};
+
+#[cfg(feature = "unsafe")]
+pub use r#unsafe::{Drain, PartialArray};
+#[cfg(feature = "unsafe")]
+use r#unsafe::{RawIter, RawIterMut};
+
+#[cfg(not(feature = "unsafe"))]
+pub use safe::{Drain, PartialArray};
+#[cfg(not(feature = "unsafe"))]
+use safe::{RawIter, RawIterMut};
@@ -0,0 +1,367 @@
+use core::fmt::{self, Debug};
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+
+use tap::Pipe;
+
+use super::{PartialArray, RawIter, RawIterMut};
+use crate::errors::capacity::{CapacityError, FixedCap, RemainingCap};
+use crate::errors::types::SizeHint;
+use crate::errors::{CollectError, ExtendError};
+use crate::{TryExtend, TryExtendOne, TryExtendSafe};
+
+impl<T, const N: usize> PartialArray<T, N> {
+    /// Returns `true` if the partial array contains no items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::partial_array::PartialArray;
+    /// let mut partial = PartialArray::<i32, 2>::new();
+    /// assert!(partial.is_empty());
+    ///
+    /// partial.try_push(1).expect("should fit");
+    /// assert!(!partial.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops all items in the partial array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use collect_failable::errors::partial_array::PartialArray;
+    /// let mut partial = PartialArray::<i32, 4>::new();
+    /// partial.try_extend([1, 2, 3]).expect("should fit");
+    ///
+    /// partial.clear();
+    /// assert!(partial.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Converts the partial array into a full array, filling any uninitialized
+    /// items with values produced by `fill`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use collect_failable::errors::partial_array::PartialArray;
+    /// let mut partial = PartialArray::<i32, 4>::new();
+    /// partial.try_extend([1, 2]).expect("should fit");
+    ///
+    /// assert_eq!(partial.try_into_array_or_else(|| 0), [1, 2, 0, 0]);
+    /// ```
+    #[must_use]
+    pub fn try_into_array_or_else<F: FnMut() -> T>(mut self, mut fill: F) -> [T; N] {
+        (self.len()..N).for_each(|_| _ = self.try_push(fill()));
+        self.try_into().unwrap_or_else(|_| unreachable!("partial array should be full"))
+    }
+}
+
+impl<T: Clone, const N: usize> PartialArray<T, N> {
+    /// Clones and appends all items in `slice` to the partial array.
+    ///
+    /// This method provides a **strong error guarantee**. If `slice` does not
+    /// fit in the remaining capacity, the partial array is not modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Bounds`](crate::errors::capacity::CapacityErrorKind::Bounds)
+    /// [`CapacityError`] if `slice` is longer than the remaining capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::capacity::{CapacityError, CapacityErrorKind};
+    /// # use collect_failable::errors::partial_array::PartialArray;
+    /// # use collect_failable::errors::types::SizeHint;
+    /// let mut partial = PartialArray::<i32, 3>::new();
+    /// partial.extend_from_slice(&[1, 2]).expect("should fit");
+    ///
+    /// let err = partial.extend_from_slice(&[3, 4]).expect_err("should not fit");
+    /// assert_eq!(err, CapacityError::bounds(SizeHint::at_most(1), SizeHint::exact(2)));
+    /// assert_eq!(partial, [1, 2][..]);
+    /// ```
+    pub fn extend_from_slice(&mut self, slice: &[T]) -> Result<(), CapacityError<T>> {
+        CapacityError::ensure_fits(&slice.iter().cloned(), self.remaining_cap())?;
+
+        slice.iter().cloned().for_each(|item| _ = self.try_push(item));
+        Ok(())
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a PartialArray<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut PartialArray<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over references to the items of a [`PartialArray`].
+///
+/// Returned by [`PartialArray::iter`].
+pub struct Iter<'a, T> {
+    iter: RawIter<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Wraps the iterator of the backing implementation.
+    pub(super) const fn new(iter: RawIter<'a, T>) -> Self {
+        Self { iter }
+    }
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { iter: self.iter.clone() }
+    }
+}
+
+impl<T: Debug> Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// An iterator over mutable references to the items of a [`PartialArray`].
+///
+/// Returned by [`PartialArray::iter_mut`].
+pub struct IterMut<'a, T> {
+    iter: RawIterMut<'a, T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Wraps the mutable iterator of the backing implementation.
+    pub(super) const fn new(iter: RawIterMut<'a, T>) -> Self {
+        Self { iter }
+    }
+}
+
+impl<T> Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IterMut").field("len", &self.iter.len()).finish_non_exhaustive()
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T, const N: usize> Default for PartialArray<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> RemainingCap for PartialArray<T, N> {
+    fn remaining_cap(&self) -> SizeHint {
+        SizeHint::at_most(N - self.len())
+    }
+}
+
+impl<T, const N: usize> FixedCap for PartialArray<T, N> {
+    const CAP: SizeHint = SizeHint::at_most(N);
+}
+
+impl<T, U, const N: usize> PartialEq<[U]> for PartialArray<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.iter().eq(other)
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<PartialArray<U, M>> for PartialArray<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &PartialArray<U, M>) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for PartialArray<T, N> {}
+
+impl<T: Hash, const N: usize> Hash for PartialArray<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for PartialArray<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        self.iter().cloned().for_each(|item| _ = clone.try_push(item));
+        clone
+    }
+}
+
+impl<T, const N: usize> TryExtendOne for PartialArray<T, N> {
+    type Item = T;
+    type Error = CapacityError<T>;
+
+    /// Forwards to [`PartialArray::try_push`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if the [`PartialArray`] is full.
+    fn try_extend_one(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        let remaining = self.remaining_cap();
+        self.try_push(item).map(drop).map_err(|item| CapacityError::overflow(remaining, item))
+    }
+}
+
+/// Extends a [`PartialArray`] with an iterator, failing if the iterator produces
+/// more items than the [`PartialArray`]'s remaining capacity.
+impl<T, const N: usize, I> TryExtend<I> for PartialArray<T, N>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = ExtendError<I::IntoIter, CapacityError<T>>;
+
+    /// Appends `iter` to the [`PartialArray`], failing if `iter` produces more
+    /// items than its [`remaining_cap`](RemainingCap::remaining_cap).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExtendError`] if `iter` produces more items than the
+    /// remaining capacity. This method provides a **basic error guarantee**.
+    /// If the method returns an error, the [`PartialArray`] is valid, but may
+    /// be modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+    fn try_extend(&mut self, iter: I) -> Result<(), Self::Error> {
+        let iter = iter.into_iter();
+
+        ExtendError::ensure_fits_into(iter, self).and_then(|mut iter| {
+            let len = self.len();
+            let remaining = self.remaining_cap();
+
+            iter.try_for_each(|item| self.try_push(item).map(drop))
+                .map_err(|item| ExtendError::new(iter, CapacityError::overflow(remaining, item)).with_position(N - len))
+        })
+    }
+}
+
+/// Extends a [`PartialArray`] with strong error guarantee.
+impl<T, const N: usize, I> TryExtendSafe<I> for PartialArray<T, N>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = CollectError<I::IntoIter, Self, CapacityError<T>>;
+
+    /// Appends `iter` to the [`PartialArray`], failing if `iter` produces more
+    /// items than its [`remaining_cap`](RemainingCap::remaining_cap).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] if `iter` produces more items than the
+    /// remaining capacity. This method provides a **strong error guarantee**.
+    /// In the case of an error, the [`PartialArray`] is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::{TryExtend, TryExtendSafe};
+    /// # use collect_failable::errors::partial_array::PartialArray;
+    /// # use size_hinter::SizeHinter;
+    /// let mut partial = PartialArray::<i32, 3>::new();
+    /// partial.try_extend_safe([1, 2]).expect("should fit");
+    ///
+    /// let err = partial.try_extend_safe([3, 4].into_iter().hide_size()).expect_err("should overflow");
+    /// assert_eq!(partial, [1, 2][..], "partial array should be unchanged on error");
+    /// assert_eq!(err.into_iter().collect::<Vec<_>>(), [4, 3], "no items should be lost");
+    /// ```
+    fn try_extend_safe(&mut self, iter: I) -> Result<(), Self::Error> {
+        let iter = iter.into_iter();
+
+        CollectError::ensure_fits_into(iter, self).and_then(|mut iter| {
+            let len = self.len();
+
+            iter.try_for_each(|item| self.try_push(item).map(drop))
+                .map_err(|item| CollectError::overflow_remaining_cap(iter, self.split_off(len), item, self).with_position(N - len))
+        })
+    }
+}
+
+/// Error returned when trying to convert a [`PartialArray`] into a full array.
+#[derive(Debug, thiserror::Error)]
+#[error("Not enough elements to fill the array: {error}")]
+pub struct IntoArrayError<T, const N: usize> {
+    /// The [`PartialArray`] that failed to convert.
+    pub partial_array: PartialArray<T, N>,
+    /// The error showing why the array was not full.
+    #[source]
+    pub error: CapacityError<T>,
+}
+
+impl<T, const N: usize> IntoArrayError<T, N> {
+    /// Creates a new [`IntoArrayError`].
+    pub(super) fn new(partial_array: PartialArray<T, N>) -> Self {
+        CapacityError::underflow_of::<[T; N]>(partial_array.len()).pipe(|error| Self { partial_array, error })
+    }
+}
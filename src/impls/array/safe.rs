@@ -0,0 +1,157 @@
+use core::iter::{FusedIterator, Map, Take};
+use core::slice;
+
+use fluent_result::into::IntoResult;
+use tap::Pipe;
+
+use super::array_index::ArrayIndex;
+use super::{IntoArrayError, Iter, IterMut};
+
+/// A possibly partially filled array with a fixed capacity of `N`.
+///
+/// This type results from the failed collection of an array, but can also be
+/// used directly as a stack allocated, fixed-capacity builder for `[T; N]`.
+///
+/// This is the safe implementation, used when the `unsafe` feature is disabled.
+/// Items are staged as [`Option<T>`], so unlike the `unsafe` implementation it
+/// does not [`Deref`](core::ops::Deref) to a slice. Both implementations provide
+/// the same inherent methods and iterators, so code using them builds either way.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryExtend;
+/// # use collect_failable::errors::partial_array::PartialArray;
+/// let mut partial = PartialArray::<i32, 4>::new();
+/// partial.try_extend([1, 2]).expect("should fit");
+/// partial.extend_from_slice(&[3]).expect("should fit");
+///
+/// assert_eq!(partial.pop(), Some(3));
+/// assert_eq!(partial.try_into_array_or_else(|| 0), [1, 2, 0, 0]);
+/// ```
+#[derive(Debug)]
+pub struct PartialArray<T, const N: usize> {
+    /// The array elements. Elements `array[..back]` are [`Some`] while `array[back..]` are [`None`]
+    array: [Option<T>; N],
+    /// Index of the first [`None`] element/length of the filled array
+    back: ArrayIndex<N>,
+}
+
+/// The iterator wrapped by [`Iter`].
+pub(super) type RawIter<'a, T> = Map<slice::Iter<'a, Option<T>>, fn(&'a Option<T>) -> &'a T>;
+
+/// The iterator wrapped by [`IterMut`].
+pub(super) type RawIterMut<'a, T> = Map<slice::IterMut<'a, Option<T>>, fn(&'a mut Option<T>) -> &'a mut T>;
+
+impl<T, const N: usize> PartialArray<T, N> {
+    /// Creates a new, empty [`PartialArray`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { array: [const { None }; N], back: ArrayIndex::new() }
+    }
+
+    /// Pushes an item into the partial array, returning it if the array is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns the item if the array is full.
+    pub fn try_push(&mut self, item: T) -> Result<&mut T, T> {
+        match self.back.try_post_inc() {
+            Some(idx) => self.array[idx].insert(item).into_ok(),
+            None => Err(item),
+        }
+    }
+
+    /// Removes the last item from the partial array and returns it, or [`None`] if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.back.try_pre_dec().map(|idx| self.array[idx].take().expect("item should be filled"))
+    }
+
+    /// Shortens the partial array, keeping the first `len` items and dropping the rest.
+    ///
+    /// If `len` is greater or equal to the current length, this has no effect.
+    pub fn truncate(&mut self, len: usize) {
+        let back = *self.back;
+        if len < back {
+            self.back = len.into();
+            self.array[len..back].iter_mut().for_each(|item| drop(item.take()));
+        }
+    }
+
+    /// Returns the number of items in the partial array.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        *self.back
+    }
+
+    /// Returns an iterator over references to the items.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.array[..*self.back].iter().map(|item| item.as_ref().expect("item should be filled")))
+    }
+
+    /// Returns an iterator over mutable references to the items.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.array[..*self.back].iter_mut().map(|item| item.as_mut().expect("item should be filled")))
+    }
+
+    /// Moves the items from `at` onward into a new [`PartialArray`].
+    pub(super) fn split_off(&mut self, at: usize) -> Self {
+        let mut tail = Self::new();
+        let back = *self.back;
+        if at < back {
+            self.back = at.into();
+            self.array[at..back].iter_mut().filter_map(Option::take).for_each(|item| _ = tail.try_push(item));
+        }
+        tail
+    }
+}
+
+/// Tries to convert the [`PartialArray`] into a full array `[T; N]`.
+///
+/// This is only possible in an overflow error case, where the iterator was too long.
+impl<T, const N: usize> TryFrom<PartialArray<T, N>> for [T; N] {
+    type Error = IntoArrayError<T, N>;
+
+    /// Performs the conversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IntoArrayError`] if the array is not full.
+    fn try_from(partial_array: PartialArray<T, N>) -> Result<Self, Self::Error> {
+        match *partial_array.back == N {
+            false => IntoArrayError::new(partial_array).into_err(),
+            true => partial_array.array.map(|item| item.expect("item should be filled")).into_ok(),
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for PartialArray<T, N> {
+    type Item = T;
+    type IntoIter = Drain<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let back = *self.back;
+        self.array.into_iter().take(back).pipe(|iter| Drain { iter })
+    }
+}
+
+/// An iterator that moves out of a [`PartialArray`].
+#[derive(Debug)]
+pub struct Drain<T, const N: usize> {
+    iter: Take<core::array::IntoIter<Option<T>, N>>,
+}
+
+impl<T, const N: usize> Iterator for Drain<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| item.expect("item should be filled"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<T, N> {}
+impl<T, const N: usize> FusedIterator for Drain<T, N> {}
@@ -4,9 +4,7 @@ use crate::TryFromIterator;
 use crate::errors::capacity::{FixedCap, RemainingCap};
 use crate::errors::types::SizeHint;
 use crate::errors::{CapacityError, CollectError};
-use crate::impls::r#unsafe::IntoArrayError;
-
-use super::PartialArray;
+use super::{IntoArrayError, PartialArray};
 
 impl<const N: usize, T> RemainingCap for [T; N] {
     /// Always returns [`SizeHint::ZERO`].
@@ -60,7 +58,7 @@ where
 
         CollectError::ensure_fits_in::<Self>(into_iter.into_iter()).and_then(|mut iter| {
             match iter.try_for_each(|item| partial_array.try_push(item).map(drop)) {
-                Err(item) => CollectError::collect_overflow::<[T; N]>(iter, partial_array, item).into_err(),
+                Err(item) => CollectError::collect_overflow::<[T; N]>(iter, partial_array, item).with_position(N).into_err(),
                 Ok(()) => partial_array
                     .try_into()
                     .map_err(|IntoArrayError { partial_array, error }| CollectError::new(iter, partial_array, error)),
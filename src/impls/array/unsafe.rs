@@ -0,0 +1,270 @@
+use core::iter::FusedIterator;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::slice;
+
+use fluent_result::into::IntoResult;
+use tap::Pipe;
+
+use super::array_index::ArrayIndex;
+use super::{IntoArrayError, Iter, IterMut};
+use crate::errors::types::SizeHint;
+
+/// A possibly partially initialized array with a fixed capacity of `N`.
+///
+/// This type results from the failed collection of an array, but can also be
+/// used directly as a stack allocated, fixed-capacity builder for `[T; N]`.
+///
+/// Derefs to a slice of the items. When the `unsafe` feature is disabled, a safe
+/// implementation is used instead, which stages items as [`Option<T>`] and does not
+/// implement [`Deref`] or [`DerefMut`]. Both implementations provide the same inherent
+/// methods, such as [`PartialArray::len`], [`PartialArray::iter`] and
+/// [`PartialArray::iter_mut`], which return the same [`Iter`] and [`IterMut`] types.
+/// Code that must build either way should use these, rather than the slice.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryExtend;
+/// # use collect_failable::errors::partial_array::PartialArray;
+/// let mut partial = PartialArray::<i32, 4>::new();
+/// partial.try_extend([1, 2]).expect("should fit");
+/// partial.extend_from_slice(&[3]).expect("should fit");
+///
+/// assert_eq!(partial.pop(), Some(3));
+/// assert_eq!(partial.try_into_array_or_else(|| 0), [1, 2, 0, 0]);
+/// ```
+#[derive(Debug)]
+pub struct PartialArray<T, const N: usize> {
+    /// The array elements. Elements `array[..back]` are initialized while `array[back..]` are uninitialized
+    array: [MaybeUninit<T>; N],
+    /// Index of the first uninitialized element/length of the initialized array
+    back: ArrayIndex<N>,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    /// Creates a new, empty [`PartialArray`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::partial_array::PartialArray;
+    /// let partial = PartialArray::<i32, 3>::new();
+    /// assert!(partial.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { array: [const { MaybeUninit::uninit() }; N], back: ArrayIndex::new() }
+    }
+
+    /// Pushes an item into the partial array, returning it if the array is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns the item if the array is full.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::partial_array::PartialArray;
+    /// let mut partial = PartialArray::<i32, 1>::new();
+    /// assert_eq!(partial.try_push(1), Ok(&mut 1));
+    /// assert_eq!(partial.try_push(2), Err(2));
+    /// ```
+    pub fn try_push(&mut self, item: T) -> Result<&mut T, T> {
+        match self.back.try_post_inc() {
+            Some(idx) => self.array[idx].write(item).into_ok(),
+            None => Err(item),
+        }
+    }
+
+    /// Removes the last item from the partial array and returns it, or [`None`] if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::errors::partial_array::PartialArray;
+    /// let mut partial = PartialArray::<i32, 2>::new();
+    /// partial.try_push(1).expect("should fit");
+    ///
+    /// assert_eq!(partial.pop(), Some(1));
+    /// assert_eq!(partial.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        self.back
+            .try_pre_dec()
+            .map(|idx| &self.array[idx])
+            // SAFETY: item was initialized, and is now outside the initialized range, so it is read once and never again
+            .map(|item| unsafe { item.assume_init_read() })
+    }
+
+    /// Shortens the partial array, keeping the first `len` items and dropping the rest.
+    ///
+    /// If `len` is greater or equal to the current length, this has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtend;
+    /// # use collect_failable::errors::partial_array::PartialArray;
+    /// let mut partial = PartialArray::<i32, 4>::new();
+    /// partial.try_extend([1, 2, 3]).expect("should fit");
+    ///
+    /// partial.truncate(1);
+    /// assert_eq!(*partial, [1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        let back = *self.back;
+        if len < back {
+            // shrink before dropping, so a panicking drop can not cause a double drop
+            self.back = len.into();
+            self.array[len..back]
+                // SAFETY: elements between `len` and `back` are initialized, and are no longer tracked
+                .pipe_ref_mut(|slice| unsafe { slice.assume_init_drop() });
+        }
+    }
+
+    /// Returns the number of items in the partial array.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        *self.back
+    }
+
+    /// Returns an iterator over references to the items.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self[..].iter())
+    }
+
+    /// Returns an iterator over mutable references to the items.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self[..].iter_mut())
+    }
+
+    /// Moves the items from `at` onward into a new [`PartialArray`].
+    pub(super) fn split_off(&mut self, at: usize) -> Self {
+        let mut tail = Self::new();
+        let back = *self.back;
+        if at < back {
+            self.back = at.into();
+            self.array[at..back].iter().for_each(|item| {
+                // SAFETY: items between `at` and `back` are initialized, are no longer tracked, and are read once
+                _ = tail.try_push(unsafe { item.assume_init_read() });
+            });
+        }
+        tail
+    }
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    /// Returns a slice of the initialized elements.
+    fn initialized(&self) -> &[MaybeUninit<T>] {
+        &self.array[..*self.back]
+    }
+
+    /// Returns a mutable slice of the initialized elements.
+    fn initialized_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        &mut self.array[..*self.back]
+    }
+}
+
+impl<T, const N: usize> Deref for PartialArray<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: initialized elements are initialized
+        self.initialized().pipe_ref(|slice| unsafe { slice.assume_init_ref() })
+    }
+}
+
+impl<T, const N: usize> DerefMut for PartialArray<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: initialized elements are initialized
+        self.initialized_mut().pipe_ref_mut(|slice| unsafe { slice.assume_init_mut() })
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: initialized elements are initialized
+        self.initialized_mut().pipe_ref_mut(|slice| unsafe { slice.assume_init_drop() });
+    }
+}
+
+/// Tries to convert the [`PartialArray`] into a full array `[T; N]`.
+///
+/// This is only possible in an overflow error case, where the iterator was too long.
+impl<T, const N: usize> TryFrom<PartialArray<T, N>> for [T; N] {
+    type Error = IntoArrayError<T, N>;
+
+    /// Performs the conversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IntoArrayError`] if the array is not fully initialized.
+    fn try_from(partial_array: PartialArray<T, N>) -> Result<Self, Self::Error> {
+        match *partial_array.back == N {
+            false => IntoArrayError::new(partial_array).into_err(),
+            true => ManuallyDrop::new(partial_array) // TODO: use `array_assume_init` when stable
+                .array
+                .as_ptr()
+                .cast::<[T; N]>()
+                // SAFETY: all elements are initialized, read exactly once and never again
+                .pipe(|ptr| unsafe { core::ptr::read(ptr) })
+                .into_ok(),
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for PartialArray<T, N> {
+    type Item = T;
+    type IntoIter = Drain<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Drain { partial_array: ManuallyDrop::new(self), next: ArrayIndex::new() }
+    }
+}
+
+/// The iterator wrapped by [`Iter`].
+pub(super) type RawIter<'a, T> = slice::Iter<'a, T>;
+
+/// The iterator wrapped by [`IterMut`].
+pub(super) type RawIterMut<'a, T> = slice::IterMut<'a, T>;
+
+/// An iterator that moves out of a [`PartialArray`].
+#[derive(Debug)]
+pub struct Drain<T, const N: usize> {
+    partial_array: ManuallyDrop<PartialArray<T, N>>,
+    next: ArrayIndex<N>,
+}
+
+impl<T, const N: usize> Iterator for Drain<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.next < self.partial_array.back).then(|| {
+            self.next
+                .try_post_inc()
+                .expect("index should be within bounds")
+                .pipe(|idx| &self.partial_array.array[idx])
+                // SAFETY: item is initialized, read once and never again
+                .pipe(|item| unsafe { item.assume_init_read() })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        SizeHint::exact(*self.partial_array.back - *self.next).into()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<T, N> {}
+impl<T, const N: usize> FusedIterator for Drain<T, N> {}
+
+impl<T, const N: usize> Drop for Drain<T, N> {
+    fn drop(&mut self) {
+        let back = *self.partial_array.back;
+
+        self.partial_array.array[*self.next..back]
+            // SAFETY: elements between `next` and `back` are owned and initialized
+            .pipe_ref_mut(|slice| unsafe { slice.assume_init_drop() });
+    }
+}
@@ -8,7 +8,7 @@ use crate::errors::capacity::{FixedCap, RemainingCap};
 use crate::errors::types::SizeHint;
 use crate::errors::{CapacityError, CollectError, ExtendError};
 use crate::impls::ensure_empty::{EnsureEmpty, NotEmpty};
-use crate::{TryExtend, TryExtendSafe, TryFromIterator};
+use crate::{InsertionOrder, TryExtend, TryExtendCloned, TryExtendClonedSafe, TryExtendSafe, TryFromIterator};
 
 impl<T, const N: usize> RemainingCap for ArrayVec<T, N> {
     fn remaining_cap(&self) -> SizeHint {
@@ -55,7 +55,7 @@ where
 
             match iter.ensure_empty() {
                 Ok(()) => Ok(array_vec),
-                Err(NotEmpty { iter, item }) => CollectError::collect_overflow::<Self>(iter, array_vec, item).into_err(),
+                Err(NotEmpty { iter, item }) => CollectError::collect_overflow::<Self>(iter, array_vec, item).with_position(N).into_err(),
             }
         })
     }
@@ -101,8 +101,11 @@ where
     fn try_extend(&mut self, iter: I) -> Result<(), Self::Error> {
         let iter = iter.into_iter();
 
-        ExtendError::ensure_fits_into(iter, self)
-            .and_then(|mut iter| iter.try_for_each(|item| self.try_extend_one(item)).map_err(|err| ExtendError::new(iter, err)))
+        ExtendError::ensure_fits_into(iter, self).and_then(|mut iter| {
+            let len = self.len();
+
+            iter.try_for_each(|item| self.try_extend_one(item)).map_err(|err| ExtendError::new(iter, err).with_position(N - len))
+        })
     }
 }
 
@@ -150,11 +153,127 @@ where
             let len = self.len();
 
             iter.try_for_each(|item| self.try_push(item))
-                .map_err(|err| CollectError::overflow_remaining_cap(iter, self.drain(len..).collect(), err.element(), self))
+                .map_err(|err| {
+                    CollectError::overflow_remaining_cap(iter, self.drain(len..).collect(), err.element(), self).with_position(N - len)
+                })
+        })
+    }
+}
+
+/// Extends an [`ArrayVec`] with clones of borrowed items, failing if the iterator produces
+/// more items than the [`ArrayVec`]'s remaining capacity.
+impl<'a, T: Clone + 'a, const N: usize, I> TryExtendCloned<I> for ArrayVec<T, N>
+where
+    I: IntoIterator<Item = &'a T>,
+{
+    type Error = ExtendError<I::IntoIter, CapacityError<&'a T>>;
+
+    /// Appends clones of `iter`'s items to the [`ArrayVec`], failing if `iter` produces more
+    /// items than [`ArrayVec::remaining_capacity`]. The overflowing item is not cloned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExtendError`] if `iter` produces more items than [`ArrayVec::remaining_capacity`].
+    /// This method provides a **basic error guarantee**. If the method returns
+    /// an error, the [`ArrayVec`] is valid, but may be modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use arrayvec::ArrayVec;
+    /// # use collect_failable::TryExtendCloned;
+    /// let source = [String::from("a"), String::from("b"), String::from("c")];
+    /// let mut array: ArrayVec<String, 2> = ArrayVec::new();
+    ///
+    /// let err = array.try_extend_cloned(source.iter().filter(|_| true)).expect_err("should overflow");
+    /// assert_eq!(*array, ["a", "b"], "accepted items should be cloned");
+    /// assert_eq!(err.into_iter().collect::<Vec<_>>(), [&source[2]], "overflowing item should be borrowed");
+    /// ```
+    fn try_extend_cloned(&mut self, iter: I) -> Result<(), Self::Error> {
+        let iter = iter.into_iter();
+
+        ExtendError::ensure_fits_into(iter, self).and_then(|mut iter| {
+            let len = self.len();
+
+            iter.try_for_each(|item| match self.is_full() {
+                true => Err(item),
+                false => {
+                    self.push(item.clone());
+                    Ok(())
+                }
+            })
+            .map_err(|item| ExtendError::overflow(iter, item).with_position(N - len))
         })
     }
 }
 
+/// Extends an [`ArrayVec`] with clones of borrowed items, with strong error guarantee.
+impl<'a, T: Clone + 'a, const N: usize, I> TryExtendClonedSafe<I> for ArrayVec<T, N>
+where
+    I: IntoIterator<Item = &'a T>,
+{
+    type Error = CollectError<I::IntoIter, ArrayVec<&'a T, N>, CapacityError<&'a T>>;
+
+    /// Appends clones of `iter`'s items to the [`ArrayVec`], failing if `iter` produces more
+    /// items than [`ArrayVec::remaining_capacity`].
+    ///
+    /// The borrowed items are staged until `iter` is exhausted, so no item is cloned on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] holding the staged borrowed items if `iter` produces more items
+    /// than [`ArrayVec::remaining_capacity`]. This method provides a **strong error guarantee**.
+    /// In the case of an error, the [`ArrayVec`] is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use arrayvec::ArrayVec;
+    /// # use collect_failable::TryExtendClonedSafe;
+    /// let source = [String::from("a"), String::from("b"), String::from("c")];
+    /// let mut array: ArrayVec<String, 2> = ArrayVec::new();
+    ///
+    /// let err = array.try_extend_cloned_safe(source.iter().filter(|_| true)).expect_err("should overflow");
+    /// assert!(array.is_empty(), "array should be unchanged on error");
+    /// assert_eq!(err.collected.len(), 2, "staged items should be borrowed");
+    /// assert_eq!(err.into_iter().count(), 3, "no items should be lost");
+    /// ```
+    fn try_extend_cloned_safe(&mut self, iter: I) -> Result<(), Self::Error> {
+        let mut iter = iter.into_iter();
+
+        if let Err(error) = CapacityError::ensure_fits(&iter, self.remaining_cap()) {
+            return CollectError::new(iter, ArrayVec::new(), error).into_err();
+        }
+
+        let remaining = self.remaining_capacity();
+        let mut staging = ArrayVec::<&'a T, N>::new();
+
+        let result = iter.try_for_each(|item| match staging.len() == remaining {
+            true => Err(item),
+            false => {
+                staging.push(item);
+                Ok(())
+            }
+        });
+
+        match result {
+            Ok(()) => {
+                self.extend(staging.into_iter().cloned());
+                Ok(())
+            }
+            Err(item) => CollectError::overflow_remaining_cap(iter, staging, item, self).with_position(remaining).into_err(),
+        }
+    }
+}
+
 impl<T, const N: usize> crate::TryExtendOne for ArrayVec<T, N> {
     type Item = T;
     type Error = CapacityError<T>;
@@ -168,3 +287,5 @@ impl<T, const N: usize> crate::TryExtendOne for ArrayVec<T, N> {
         self.try_push(item).map_err(Into::into)
     }
 }
+
+impl<T, const N: usize> InsertionOrder for ArrayVec<T, N> {}
@@ -1,12 +1,14 @@
 use crate::TryExtendOne;
 
 /// Extends a collection via [`TryExtendOne::try_extend_one`], erroring if any call fails.
-pub fn try_extend_basic<T, C, I>(map: &mut C, iter: &mut I) -> Result<(), C::Error>
+///
+/// On failure, returns the zero-based position of the failing item in `iter` along with the error.
+pub fn try_extend_basic<T, C, I>(map: &mut C, iter: &mut I) -> Result<(), (usize, C::Error)>
 where
     I: Iterator<Item = T>,
     C: TryExtendOne<Item = T>,
 {
-    iter.try_for_each(|item| map.try_extend_one(item))
+    iter.enumerate().try_for_each(|(position, item)| map.try_extend_one(item).map_err(|err| (position, err)))
 }
 
 /// Implements [`TryFromIterator`] via [`TryExtendOne::try_extend_one`].
@@ -54,7 +56,7 @@ macro_rules! impl_try_from_iter_via_try_extend_one {
 
                 match $crate::impls::macros::try_extend_basic(&mut collection, &mut iter) {
                     Ok(()) => Ok(collection),
-                    Err(err) => Err($crate::errors::CollectError::new(iter, collection, err)),
+                    Err((position, err)) => Err($crate::errors::CollectError::new(iter, collection, err).with_position(position)),
                 }
             }
         }
@@ -111,7 +113,7 @@ macro_rules! impl_try_extend_via_try_extend_one {
                 $crate::impls::macros::infer_reserve::<I, Self, _>($reserve)(self, &mut iter);
 
                 $crate::impls::macros::try_extend_basic(self, &mut iter)
-                    .map_err(|err| $crate::errors::ExtendError::new(iter, err))
+                    .map_err(|(position, err)| $crate::errors::ExtendError::new(iter, err).with_position(position))
             }
         }
     };
@@ -128,7 +130,8 @@ pub const fn infer_reserve<I: IntoIterator, C, F: Fn(&mut C, &I::IntoIter)>(f: F
 /// impl_try_extend_safe_for_colliding_type!(
 ///     type: $type where [$($generics)*] of $item:ty;
 ///     build_staging: $build_staging;
-///     contains: $contains
+///     contains: $contains;
+///     merge: $merge // optional
 /// );
 /// ```
 ///
@@ -141,11 +144,27 @@ pub const fn infer_reserve<I: IntoIterator, C, F: Fn(&mut C, &I::IntoIter)>(f: F
 ///   - `fn(iter: &I::IntoIter, set: &Self) -> Self`
 /// * `contains`: A function that checks if the collection contains an item.
 ///   - `fn(&Self, &$item) -> bool`
+/// * `merge`: A function that moves the staged items into the collection. Defaults to
+///   [`Extend::extend`].
+///   - `fn(&mut Self, Self)`
 macro_rules! impl_try_extend_safe_for_colliding_type {
     (
         type: $type:ty where [$($generics:tt)*] of $item:ty;
         build_staging: $build_staging:expr;
         contains: $contains:expr
+    ) => {
+        $crate::impls::macros::impl_try_extend_safe_for_colliding_type!(
+            type: $type where [$($generics)*] of $item;
+            build_staging: $build_staging;
+            contains: $contains;
+            merge: |collection, staging| ::core::iter::Extend::extend(collection, staging)
+        );
+    };
+    (
+        type: $type:ty where [$($generics:tt)*] of $item:ty;
+        build_staging: $build_staging:expr;
+        contains: $contains:expr;
+        merge: $merge:expr
     ) => {
         impl<$($generics)*, I> $crate::TryExtendSafe<I> for $type
         where
@@ -159,19 +178,19 @@ macro_rules! impl_try_extend_safe_for_colliding_type {
                 let staging = $crate::impls::macros::infer_build_staging::<Self, I, _>($build_staging)(&iter, self);
                 let contains = $crate::impls::macros::infer_contains::<Self, $item, _>($contains);
 
-                iter.try_fold(staging, |mut staging, item| {
+                iter.by_ref().enumerate().try_fold(staging, |mut staging, (position, item)| {
                     // check for an entry in the main map
                     match contains(self, &item) {
-                        true => Err((staging, $crate::errors::collision::Collision::new(item))),
+                        true => Err((staging, position, $crate::errors::collision::Collision::new(item))),
                         // try to add to the staging map
                         false => match $crate::TryExtendOne::try_extend_one(&mut staging, item) {
                             Ok(()) => Ok(staging),
-                            Err(err) => Err((staging, err)),
+                            Err(err) => Err((staging, position, err)),
                         },
                     }
                 })
-                .map(|staging| ::core::iter::Extend::extend(self, staging))
-                .map_err(|(staging, err)| $crate::errors::CollectError::new(iter, staging, err))
+                .map(|staging| $crate::impls::macros::infer_merge::<Self, _>($merge)(self, staging))
+                .map_err(|(staging, position, err)| $crate::errors::CollectError::new(iter, staging, err).with_position(position))
             }
         }
     };
@@ -187,6 +206,152 @@ pub const fn infer_contains<C, T, F: Fn(&C, &T) -> bool>(f: F) -> F {
     f
 }
 
+/// Helper function to infer the type of the merge function.
+#[cfg(feature = "alloc")]
+pub const fn infer_merge<C, F: Fn(&mut C, C)>(f: F) -> F {
+    f
+}
+
+/// Implements [`TryExtendInPlace`] for types that cannot contain colliding items, by inserting
+/// items directly via [`TryExtendOne::try_extend_one`] and undoing the insertions on failure.
+///
+/// The removed items are returned as the collected values of a [`CollectError`], as with
+/// [`TryExtendSafe`]. The insertions are undone in one of two ways:
+///
+/// * `key` and `remove`: the key of each inserted item is recorded in an undo log, and each
+///   logged key is removed on failure. Logging usually clones every key, even on success.
+/// * `split_off`: for types that append new items in insertion order, the inserted items are the
+///   last items in the collection, and are split off by index, without logging anything.
+///
+/// ```text
+/// impl_try_extend_in_place_via_undo_log!(
+///     type: $type where [$($generics)*] of $item:ty;
+///     reserve: $reserve;
+///     empty: $empty;
+///     key: $key;
+///     remove: $remove
+/// );
+///
+/// impl_try_extend_in_place_via_undo_log!(
+///     type: $type where [$($generics)*] of $item:ty;
+///     reserve: $reserve;
+///     split_off: $split_off
+/// );
+/// ```
+///
+/// # Arguments
+///
+/// * `type`: The type to implement [`TryExtendInPlace`] for.
+/// * `generics`: The generics for the type.
+/// * `item`: The item type.
+/// * `reserve`: A function that reserves space in the collection.
+///   - `fn(&mut Self, &I::IntoIter)`
+/// * `empty`: A function that builds an empty collection, to hold the removed items.
+///   - `fn(&Self) -> Self`
+/// * `key`: A function that returns the key to log for an item.
+///   - `fn(&$item) -> K`
+/// * `remove`: A function that removes the item with a logged key from the collection.
+///   - `fn(&mut Self, K) -> Option<$item>`
+/// * `split_off`: A function that splits off a number of items from the end of the collection.
+///   - `fn(&mut Self, usize) -> Self`
+#[cfg(feature = "alloc")]
+macro_rules! impl_try_extend_in_place_via_undo_log {
+    (
+        type: $type:ty where [$($generics:tt)*] of $item:ty;
+        reserve: $reserve:expr;
+        empty: $empty:expr;
+        key: $key:expr;
+        remove: $remove:expr
+    ) => {
+        impl<$($generics)*, I> $crate::TryExtendInPlace<I> for $type
+        where
+            I: IntoIterator<Item = $item>,
+        {
+            type Error = $crate::errors::CollectError<I::IntoIter, Self, <Self as $crate::TryExtendOne>::Error>;
+
+            fn try_extend_in_place(&mut self, iter: I) -> Result<(), Self::Error> {
+                let mut iter = iter.into_iter();
+
+                $crate::impls::macros::infer_reserve::<I, Self, _>($reserve)(self, &mut iter);
+
+                let key = $crate::impls::macros::infer_key::<$item, _, _>($key);
+                let log = ::alloc::vec::Vec::with_capacity($crate::prealloc::hint(&iter));
+
+                iter.by_ref()
+                    .enumerate()
+                    .try_fold(log, |mut log, (position, item)| {
+                        let logged = key(&item);
+                        match $crate::TryExtendOne::try_extend_one(self, item) {
+                            Ok(()) => {
+                                log.push(logged);
+                                Ok(log)
+                            }
+                            Err(err) => Err((log, position, err)),
+                        }
+                    })
+                    .map(|_| ())
+                    .map_err(|(log, position, err)| {
+                        // undo every insertion, moving the inserted items into the staging collection
+                        let remove = $crate::impls::macros::infer_remove::<Self, $item, _, _>($remove);
+                        let mut staging = $crate::impls::macros::infer_empty::<Self, _>($empty)(self);
+                        ::core::iter::Extend::extend(&mut staging, log.into_iter().filter_map(|key| remove(self, key)));
+
+                        $crate::errors::CollectError::new(iter, staging, err).with_position(position)
+                    })
+            }
+        }
+    };
+    (
+        type: $type:ty where [$($generics:tt)*] of $item:ty;
+        reserve: $reserve:expr;
+        split_off: $split_off:expr
+    ) => {
+        impl<$($generics)*, I> $crate::TryExtendInPlace<I> for $type
+        where
+            I: IntoIterator<Item = $item>,
+        {
+            type Error = $crate::errors::CollectError<I::IntoIter, Self, <Self as $crate::TryExtendOne>::Error>;
+
+            fn try_extend_in_place(&mut self, iter: I) -> Result<(), Self::Error> {
+                let mut iter = iter.into_iter();
+
+                $crate::impls::macros::infer_reserve::<I, Self, _>($reserve)(self, &mut iter);
+
+                $crate::impls::macros::try_extend_basic(self, &mut iter).map_err(|(position, err)| {
+                    // every item before `position` was appended, so they are the last `position` items
+                    let staging = $crate::impls::macros::infer_split_off::<Self, _>($split_off)(self, position);
+
+                    $crate::errors::CollectError::new(iter, staging, err).with_position(position)
+                })
+            }
+        }
+    };
+}
+
+/// Helper function to infer the type of the split off function.
+#[cfg(any(feature = "indexmap", feature = "ordermap"))]
+pub const fn infer_split_off<C, F: Fn(&mut C, usize) -> C>(f: F) -> F {
+    f
+}
+
+/// Helper function to infer the type of the empty function.
+#[cfg(feature = "alloc")]
+pub const fn infer_empty<C, F: Fn(&C) -> C>(f: F) -> F {
+    f
+}
+
+/// Helper function to infer the type of the key function.
+#[cfg(feature = "alloc")]
+pub const fn infer_key<T, K, F: Fn(&T) -> K>(f: F) -> F {
+    f
+}
+
+/// Helper function to infer the type of the remove function.
+#[cfg(feature = "alloc")]
+pub const fn infer_remove<C, T, K, F: Fn(&mut C, K) -> Option<T>>(f: F) -> F {
+    f
+}
+
 /// Implements [`TryExtendOne`] for types that cannot contain colliding items.
 ///
 /// ```text
@@ -234,7 +399,169 @@ pub const fn infer_insert<C, T, F: Fn(&mut C, T)>(f: F) -> F {
     f
 }
 
+/// Implements [`TryExtendOne`] for sets that provide a `replace` method, which inserts an item
+/// with a single lookup, returning any equal item it replaced.
+///
+/// On a collision, the replaced item is swapped back in, so the set is unchanged, and the
+/// rejected item is returned intact. A colliding item therefore costs two `replace` lookups,
+/// where a `contains` check followed by `insert` needs only the one `contains` lookup, but
+/// every item that does not collide saves a lookup.
+///
+/// ```text
+/// impl_try_extend_one_via_replace!(
+///     type: $type where [$generics] of $item;
+///     replace: $replace
+/// );
+/// ```
+///
+/// # Arguments
+///
+/// * `type`: The type to implement [`TryExtendOne`] for.
+/// * `generics`: The generics for the type.
+/// * `item`: The item type.
+/// * `replace`: A function that inserts an item, returning any equal item it replaced.
+///   - `fn(&mut Self, $item) -> Option<$item>`
+#[cfg(feature = "alloc")]
+macro_rules! impl_try_extend_one_via_replace {
+    (
+        type: $type:ty where [$($generics:tt)*] of $item:ty;
+        replace: $replace:expr
+    ) => {
+        impl<$($generics)*> $crate::TryExtendOne for $type {
+            type Item = $item;
+            type Error = $crate::errors::Collision<$item>;
+
+            fn try_extend_one(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+                let replace = $crate::impls::macros::infer_replace::<Self, $item, _>($replace);
+
+                match replace(self, item) {
+                    None => Ok(()),
+                    Some(original) => {
+                        let rejected = replace(self, original).expect("rejected item should be in the set");
+                        Err($crate::errors::Collision::new(rejected))
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Implements [`TryExtendCloned`] and [`TryExtendClonedSafe`] for types that cannot contain
+/// colliding items, by checking each borrowed item before cloning and inserting it.
+///
+/// [`TryExtendClonedSafe`] logs the borrowed items as they are inserted. On failure, the logged
+/// items are removed from the collection, and returned as the collected values of a
+/// [`CollectError`].
+///
+/// ```text
+/// impl_try_extend_cloned_for_colliding_type!(
+///     type: $type where ['a, $generics] of $item;
+///     reserve: $reserve;
+///     contains: $contains;
+///     insert: $insert;
+///     remove: $remove
+/// );
+/// ```
+///
+/// # Arguments
+///
+/// * `type`: The type to implement [`TryExtendCloned`] and [`TryExtendClonedSafe`] for.
+/// * `generics`: The generics for the type, including the lifetime `'a` of the borrowed items.
+/// * `item`: The borrowed item type.
+/// * `reserve`: A function that reserves space in the collection.
+///   - `fn(&mut Self, &I::IntoIter)`
+/// * `contains`: A function that checks if the collection contains a borrowed item.
+///   - `fn(&Self, &$item) -> bool`
+/// * `insert`: A function that clones a borrowed item into the collection.
+///   - `fn(&mut Self, $item)`
+/// * `remove`: A function that removes the clone of a borrowed item from the collection.
+///   - `fn(&mut Self, $item)`
+#[cfg(feature = "alloc")]
+macro_rules! impl_try_extend_cloned_for_colliding_type {
+    (
+        type: $type:ty where [$($generics:tt)*] of $item:ty;
+        reserve: $reserve:expr;
+        contains: $contains:expr;
+        insert: $insert:expr;
+        remove: $remove:expr
+    ) => {
+        impl<$($generics)*, I> $crate::TryExtendCloned<I> for $type
+        where
+            I: IntoIterator<Item = $item>,
+        {
+            type Error = $crate::errors::ExtendError<I::IntoIter, $crate::errors::Collision<$item>>;
+
+            fn try_extend_cloned(&mut self, iter: I) -> Result<(), Self::Error> {
+                let mut iter = iter.into_iter();
+
+                $crate::impls::macros::infer_reserve::<I, Self, _>($reserve)(self, &iter);
+
+                let contains = $crate::impls::macros::infer_contains::<Self, $item, _>($contains);
+                let insert = $crate::impls::macros::infer_insert::<Self, $item, _>($insert);
+
+                let result = iter.by_ref().enumerate().try_for_each(|(position, item)| match contains(self, &item) {
+                    true => Err((position, item)),
+                    false => {
+                        insert(self, item);
+                        Ok(())
+                    }
+                });
+
+                result.map_err(|(position, item)| {
+                    $crate::errors::ExtendError::new(iter, $crate::errors::Collision::new(item)).with_position(position)
+                })
+            }
+        }
+
+        impl<$($generics)*, I> $crate::TryExtendClonedSafe<I> for $type
+        where
+            I: IntoIterator<Item = $item>,
+        {
+            type Error = $crate::errors::CollectError<I::IntoIter, ::alloc::vec::Vec<$item>, $crate::errors::Collision<$item>>;
+
+            fn try_extend_cloned_safe(&mut self, iter: I) -> Result<(), Self::Error> {
+                let mut iter = iter.into_iter();
+
+                $crate::impls::macros::infer_reserve::<I, Self, _>($reserve)(self, &iter);
+
+                let contains = $crate::impls::macros::infer_contains::<Self, $item, _>($contains);
+                let insert = $crate::impls::macros::infer_insert::<Self, $item, _>($insert);
+                let mut log = ::alloc::vec::Vec::with_capacity($crate::prealloc::hint(&iter));
+
+                let result = iter.by_ref().enumerate().try_for_each(|(position, item)| match contains(self, &item) {
+                    true => Err((position, item)),
+                    false => {
+                        insert(self, item);
+                        log.push(item);
+                        Ok(())
+                    }
+                });
+
+                result.map_err(|(position, item)| {
+                    // undo every insertion, the borrowed items are still owned by the caller
+                    let remove = $crate::impls::macros::infer_insert::<Self, $item, _>($remove);
+                    log.iter().rev().for_each(|logged| remove(self, *logged));
+
+                    $crate::errors::CollectError::new(iter, log, $crate::errors::Collision::new(item)).with_position(position)
+                })
+            }
+        }
+    };
+}
+
+/// Helper function to infer the type of the replace function.
+#[cfg(feature = "alloc")]
+pub const fn infer_replace<C, T, F: Fn(&mut C, T) -> Option<T>>(f: F) -> F {
+    f
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) use impl_try_extend_cloned_for_colliding_type;
+#[cfg(feature = "alloc")]
+pub(crate) use impl_try_extend_in_place_via_undo_log;
 pub(crate) use impl_try_extend_one_for_colliding_type;
+#[cfg(feature = "alloc")]
+pub(crate) use impl_try_extend_one_via_replace;
 pub(crate) use impl_try_extend_safe_for_colliding_type;
 pub(crate) use impl_try_extend_via_try_extend_one;
 pub(crate) use impl_try_from_iter_via_try_extend_one;
@@ -2,6 +2,10 @@ use alloc::collections::BTreeMap;
 
 use fluent_result::expect::dbg::ExpectNone;
 
+use crate::errors::{CollectError, SortedError};
+use crate::impls::sorted::try_collect_sorted;
+use crate::{TryExtendSorted, TryFromSortedIterator};
+
 crate::impls::macros::impl_try_from_iter_via_try_extend_one! (
     type: BTreeMap<K, V> where [K: Ord, V] of (K, V);
     ctor: |_| Self::new()
@@ -15,11 +19,89 @@ crate::impls::macros::impl_try_extend_via_try_extend_one! (
 crate::impls::macros::impl_try_extend_safe_for_colliding_type! (
     type: BTreeMap<K, V> where [K: Ord, V] of (K, V);
     build_staging: |_, _| BTreeMap::new();
-    contains: |map, (key, _)| map.contains_key(key)
+    contains: |map, (key, _)| map.contains_key(key);
+    merge: |map, mut staging| map.append(&mut staging)
+);
+
+crate::impls::macros::impl_try_extend_in_place_via_undo_log! (
+    type: BTreeMap<K, V> where [K: Ord + Clone, V] of (K, V);
+    reserve: |_, _| {};
+    empty: |_| BTreeMap::new();
+    key: |(key, _)| key.clone();
+    remove: |map, key| map.remove_entry(&key)
+);
+
+crate::impls::macros::impl_try_extend_cloned_for_colliding_type! (
+    type: BTreeMap<K, V> where ['a, K: Ord + Clone + 'a, V: Clone + 'a] of (&'a K, &'a V);
+    reserve: |_, _| {};
+    contains: |map, (key, _)| map.contains_key(*key);
+    insert: |map, (key, value)| _ = map.insert(key.clone(), value.clone());
+    remove: |map, (key, _)| _ = map.remove(key)
 );
 
+// stable std has no entry API that returns the caller's key on a collision, so this checks
+// contains_key before inserting, rather than using a single lookup
 crate::impls::macros::impl_try_extend_one_for_colliding_type!(
     type: BTreeMap<K, V> where [K: Ord, V] of (K, V);
     contains: |map, (key, _)| map.contains_key(key);
     insert: |map, (key, value)| map.insert(key, value).expect_none("should not be in map")
 );
+
+/// Create a [`BTreeMap`] from an iterator of key-value pairs sorted by key, failing if
+/// a key collides with, or is less than, the previous key.
+///
+/// The map is built in bulk, without a lookup per item.
+impl<K: Ord, V, I> TryFromSortedIterator<I> for BTreeMap<K, V>
+where
+    I: IntoIterator<Item = (K, V)>,
+{
+    type Error = CollectError<I::IntoIter, Self, SortedError<(K, V)>>;
+
+    /// Create a [`BTreeMap`] from `into_iter`, whose keys must be strictly increasing.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] with a [`SortedError`] if a key collides with, or is less
+    /// than, the previous key. All items from `into_iter` are preserved in the error, and can
+    /// be retrieved using [`CollectError::into_iter`].
+    fn try_from_sorted_iter(into_iter: I) -> Result<Self, Self::Error> {
+        let mut iter = into_iter.into_iter();
+
+        try_collect_sorted(&mut iter, |(a, _), (b, _)| a.cmp(b), |_| false)
+            .map(Self::from_iter)
+            .map_err(|(sorted, error)| {
+                let position = sorted.len();
+                CollectError::new(iter, Self::from_iter(sorted), error).with_position(position)
+            })
+    }
+}
+
+/// Extend a [`BTreeMap`] with key-value pairs sorted by key, failing if a key collides
+/// with the map, or collides with, or is less than, the previous key.
+///
+/// The items are staged in bulk, and then merged into the map via [`BTreeMap::append`].
+impl<K: Ord, V, I> TryExtendSorted<I> for BTreeMap<K, V>
+where
+    I: IntoIterator<Item = (K, V)>,
+{
+    type Error = CollectError<I::IntoIter, Self, SortedError<(K, V)>>;
+
+    /// Extend the map with `iter`, whose keys must be strictly increasing, providing a
+    /// **strong error guarantee**.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] with a [`SortedError`] if a key collides with the map,
+    /// or collides with, or is less than, the previous key. The map is unchanged, and all
+    /// items from `iter` are preserved in the error.
+    fn try_extend_sorted(&mut self, iter: I) -> Result<(), Self::Error> {
+        let mut iter = iter.into_iter();
+
+        try_collect_sorted(&mut iter, |(a, _), (b, _)| a.cmp(b), |(key, _)| self.contains_key(key))
+            .map(|sorted| self.append(&mut Self::from_iter(sorted)))
+            .map_err(|(sorted, error)| {
+                let position = sorted.len();
+                CollectError::new(iter, Self::from_iter(sorted), error).with_position(position)
+            })
+    }
+}
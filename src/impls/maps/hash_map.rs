@@ -3,24 +3,57 @@ use std::collections::HashMap;
 
 use fluent_result::expect::dbg::ExpectNone;
 
+use crate::TryReserve;
+
 crate::impls::macros::impl_try_from_iter_via_try_extend_one! (
     type: HashMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Default] of (K, V);
-    ctor: |iter| HashMap::with_capacity_and_hasher(iter.size_hint().0, S::default())
+    ctor: |iter| HashMap::with_capacity_and_hasher(crate::prealloc::hint(iter), S::default())
 );
 
 crate::impls::macros::impl_try_extend_via_try_extend_one! (
     type: HashMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Clone] of (K, V);
-    reserve: |map, iter| map.reserve(iter.size_hint().0)
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter))
 );
 
 crate::impls::macros::impl_try_extend_safe_for_colliding_type! (
     type: HashMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Clone] of (K, V);
-    build_staging: |iter, map| HashMap::with_capacity_and_hasher(iter.size_hint().0, map.hasher().clone());
+    build_staging: |iter, map| HashMap::with_capacity_and_hasher(crate::prealloc::hint(iter), map.hasher().clone());
     contains: |map, (key, _)| map.contains_key(key)
 );
 
+crate::impls::macros::impl_try_extend_in_place_via_undo_log! (
+    type: HashMap<K, V, S> where [K: Eq + Hash + Clone, V, S: BuildHasher + Clone] of (K, V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter));
+    empty: |map| HashMap::with_hasher(map.hasher().clone());
+    key: |(key, _)| key.clone();
+    remove: |map, key| map.remove_entry(&key)
+);
+
+crate::impls::macros::impl_try_extend_cloned_for_colliding_type! (
+    type: HashMap<K, V, S> where ['a, K: Eq + Hash + Clone + 'a, V: Clone + 'a, S: BuildHasher] of (&'a K, &'a V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter));
+    contains: |map, (key, _)| map.contains_key(*key);
+    insert: |map, (key, value)| _ = map.insert(key.clone(), value.clone());
+    remove: |map, (key, _)| _ = map.remove(key)
+);
+
+// stable std has no entry API that returns the caller's key on a collision, so this checks
+// contains_key before inserting, rather than using a single lookup
 crate::impls::macros::impl_try_extend_one_for_colliding_type!(
     type: HashMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher] of (K, V);
     contains: |map, (key, _)| map.contains_key(key);
     insert: |map, (key, value)| map.insert(key, value).expect_none("should not be in map")
 );
+
+impl<K, V, S> TryReserve for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = std::collections::TryReserveError;
+
+    /// Forwards directly to [`HashMap::try_reserve`].
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        Self::try_reserve(self, additional)
+    }
+}
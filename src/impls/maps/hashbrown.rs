@@ -5,16 +5,32 @@ use hashbrown::hash_map::RawEntryMut;
 
 use crate::errors::{CollectError, Collision};
 
-use crate::{TryExtendOne, TryExtendSafe};
+use crate::{TryExtendOne, TryExtendSafe, TryReserve};
 
 crate::impls::macros::impl_try_from_iter_via_try_extend_one! (
     type: HashMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Default] of (K, V);
-    ctor: |iter| HashMap::with_capacity_and_hasher(iter.size_hint().0, S::default())
+    ctor: |iter| HashMap::with_capacity_and_hasher(crate::prealloc::hint(iter), S::default())
 );
 
 crate::impls::macros::impl_try_extend_via_try_extend_one! (
     type: HashMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Clone] of (K, V);
-    reserve: |map, iter| map.reserve(iter.size_hint().0)
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter))
+);
+
+crate::impls::macros::impl_try_extend_in_place_via_undo_log! (
+    type: HashMap<K, V, S> where [K: Eq + Hash + Clone, V, S: BuildHasher + Clone] of (K, V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter));
+    empty: |map| HashMap::with_hasher(map.hasher().clone());
+    key: |(key, _)| key.clone();
+    remove: |map, key| map.remove_entry(&key)
+);
+
+crate::impls::macros::impl_try_extend_cloned_for_colliding_type! (
+    type: HashMap<K, V, S> where ['a, K: Eq + Hash + Clone + 'a, V: Clone + 'a, S: BuildHasher] of (&'a K, &'a V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter));
+    contains: |map, (key, _)| map.contains_key(*key);
+    insert: |map, (key, value)| _ = map.insert(key.clone(), value.clone());
+    remove: |map, (key, _)| _ = map.remove(key)
 );
 
 impl<K: Eq + Hash, V, S: BuildHasher + Clone, I> TryExtendSafe<I> for HashMap<K, V, S>
@@ -26,7 +42,7 @@ where
     fn try_extend_safe(&mut self, iter: I) -> Result<(), Self::Error> {
         let mut iter = iter.into_iter();
 
-        let staging_map = Self::with_capacity_and_hasher(iter.size_hint().0, self.hasher().clone());
+        let staging_map = Self::with_capacity_and_hasher(crate::prealloc::hint(&iter), self.hasher().clone());
 
         iter.try_fold(staging_map, |mut staging_map, (key, value)| {
             let shared_hash = staging_map.hasher().hash_one(&key);
@@ -46,7 +62,10 @@ where
             }
         })
         .map(|staging_map| self.extend(staging_map))
-        .map_err(|(staging_map, kvp)| CollectError::collision(iter, staging_map, kvp))
+        .map_err(|(staging_map, kvp)| {
+            let position = staging_map.len();
+            CollectError::collision(iter, staging_map, kvp).with_position(position)
+        })
     }
 }
 
@@ -65,3 +84,16 @@ impl<K: Eq + Hash, V, S: BuildHasher> TryExtendOne for HashMap<K, V, S> {
         }
     }
 }
+
+impl<K, V, S> TryReserve for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = hashbrown::TryReserveError;
+
+    /// Forwards directly to [`HashMap::try_reserve`].
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        Self::try_reserve(self, additional)
+    }
+}
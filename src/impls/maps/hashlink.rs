@@ -0,0 +1,71 @@
+use core::hash::{BuildHasher, Hash};
+
+use hashlink::LinkedHashMap;
+use hashlink::linked_hash_map::RawEntryMut;
+
+use crate::errors::Collision;
+use crate::{InsertionOrder, TryExtendOne, TryReserve};
+
+crate::impls::macros::impl_try_from_iter_via_try_extend_one! (
+    type: LinkedHashMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Default] of (K, V);
+    ctor: |iter| LinkedHashMap::with_capacity_and_hasher(crate::prealloc::hint(iter), S::default())
+);
+
+crate::impls::macros::impl_try_extend_via_try_extend_one! (
+    type: LinkedHashMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Clone] of (K, V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter))
+);
+
+crate::impls::macros::impl_try_extend_safe_for_colliding_type! (
+    type: LinkedHashMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Clone] of (K, V);
+    build_staging: |iter, map| LinkedHashMap::with_capacity_and_hasher(crate::prealloc::hint(iter), map.hasher().clone());
+    contains: |map, (key, _)| map.contains_key(key)
+);
+
+// removal unlinks the entry, so the order of the original entries is preserved
+crate::impls::macros::impl_try_extend_in_place_via_undo_log! (
+    type: LinkedHashMap<K, V, S> where [K: Eq + Hash + Clone, V, S: BuildHasher + Clone] of (K, V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter));
+    empty: |map| LinkedHashMap::with_hasher(map.hasher().clone());
+    key: |(key, _)| key.clone();
+    remove: |map, key| map.remove_entry(&key)
+);
+
+crate::impls::macros::impl_try_extend_cloned_for_colliding_type! (
+    type: LinkedHashMap<K, V, S> where ['a, K: Eq + Hash + Clone + 'a, V: Clone + 'a, S: BuildHasher] of (&'a K, &'a V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter));
+    contains: |map, (key, _)| map.contains_key(*key);
+    insert: |map, (key, value)| _ = map.insert(key.clone(), value.clone());
+    remove: |map, (key, _)| _ = map.remove(key)
+);
+
+impl<K: Eq + Hash, V, S: BuildHasher> TryExtendOne for LinkedHashMap<K, V, S> {
+    type Item = (K, V);
+    type Error = Collision<(K, V)>;
+
+    fn try_extend_one(&mut self, (key, value): Self::Item) -> Result<(), Self::Error> {
+        let hash = self.hasher().hash_one(&key);
+        match self.raw_entry_mut().from_hash(hash, |k| k == &key) {
+            RawEntryMut::Occupied(_) => Err(Collision::new((key, value))),
+            RawEntryMut::Vacant(entry) => {
+                entry.insert_hashed_nocheck(hash, key, value);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<K, V, S> TryReserve for LinkedHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = hashlink::linked_hash_map::TryReserveError;
+
+    /// Forwards directly to [`LinkedHashMap::try_reserve`].
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        Self::try_reserve(self, additional)
+    }
+}
+
+impl<K, V, S> InsertionOrder for LinkedHashMap<K, V, S> {}
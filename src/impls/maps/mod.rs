@@ -9,3 +9,9 @@ pub mod hashbrown;
 
 #[cfg(feature = "indexmap")]
 pub mod indexmap;
+
+#[cfg(feature = "hashlink")]
+pub mod hashlink;
+
+#[cfg(feature = "ordermap")]
+pub mod ordermap;
@@ -0,0 +1,70 @@
+use core::hash::{BuildHasher, Hash};
+
+use ordermap::OrderMap;
+use ordermap::map::RawEntryApiV1;
+use ordermap::map::raw_entry_v1::RawEntryMut;
+
+use crate::errors::Collision;
+use crate::{InsertionOrder, TryExtendOne, TryReserve};
+
+crate::impls::macros::impl_try_from_iter_via_try_extend_one! (
+    type: OrderMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Default] of (K, V);
+    ctor: |iter| OrderMap::with_capacity_and_hasher(crate::prealloc::hint(iter), S::default())
+);
+
+crate::impls::macros::impl_try_extend_via_try_extend_one! (
+    type: OrderMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Clone] of (K, V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter))
+);
+
+crate::impls::macros::impl_try_extend_safe_for_colliding_type! (
+    type: OrderMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Clone] of (K, V);
+    build_staging: |iter, map| OrderMap::with_capacity_and_hasher(crate::prealloc::hint(iter), map.hasher().clone());
+    contains: |map, (key, _)| map.contains_key(key)
+);
+
+// swap removal only moves inserted entries, so the order of the original entries is preserved
+crate::impls::macros::impl_try_extend_in_place_via_undo_log! (
+    type: OrderMap<K, V, S> where [K: Eq + Hash, V, S: BuildHasher + Clone] of (K, V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter));
+    split_off: |map, inserted| map.split_off(map.len() - inserted)
+);
+
+crate::impls::macros::impl_try_extend_cloned_for_colliding_type! (
+    type: OrderMap<K, V, S> where ['a, K: Eq + Hash + Clone + 'a, V: Clone + 'a, S: BuildHasher] of (&'a K, &'a V);
+    reserve: |map, iter| map.reserve(crate::prealloc::hint(iter));
+    contains: |map, (key, _)| map.contains_key(*key);
+    insert: |map, (key, value)| _ = map.insert(key.clone(), value.clone());
+    remove: |map, (key, _)| _ = map.swap_remove(key)
+);
+
+impl<K: Eq + Hash, V, S: BuildHasher> TryExtendOne for OrderMap<K, V, S> {
+    type Item = (K, V);
+    type Error = Collision<(K, V)>;
+
+    fn try_extend_one(&mut self, (key, value): Self::Item) -> Result<(), Self::Error> {
+        let hash = self.hasher().hash_one(&key);
+        match self.raw_entry_mut_v1().from_hash(hash, |k| k == &key) {
+            RawEntryMut::Occupied(_) => Err(Collision::new((key, value))),
+            RawEntryMut::Vacant(entry) => {
+                entry.insert_hashed_nocheck(hash, key, value);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<K, V, S> TryReserve for OrderMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = ordermap::TryReserveError;
+
+    /// Forwards directly to [`OrderMap::try_reserve`].
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        Self::try_reserve(self, additional)
+    }
+}
+
+impl<K, V, S> InsertionOrder for OrderMap<K, V, S> {}
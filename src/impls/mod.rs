@@ -3,22 +3,33 @@ mod ensure_empty;
 #[doc(hidden)]
 pub use ensure_empty::*;
 
-#[cfg(feature = "unsafe")]
-pub mod r#unsafe;
+pub mod array;
 
 #[cfg(feature = "arrayvec")]
 mod arrayvec;
 
+#[cfg(feature = "nonempty")]
+mod nonempty;
+
+#[cfg(feature = "vec1")]
+mod vec1;
+
 mod maps;
 mod sets;
 
 #[cfg(feature = "alloc")]
 mod result;
 
+#[cfg(feature = "alloc")]
+mod slices;
+
+#[cfg(feature = "alloc")]
+mod sorted;
+
 #[cfg(feature = "alloc")]
 mod vec;
 
 #[cfg(feature = "tuples")]
 mod tuples;
 
-mod macros;
+pub(crate) mod macros;
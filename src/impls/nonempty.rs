@@ -0,0 +1,35 @@
+use alloc::vec::Vec;
+
+use crate::TryFromIterator;
+use crate::collections::NonEmpty;
+use crate::errors::{CapacityError, CollectError, WrapperError};
+
+/// Tries to create a [`nonempty::NonEmpty`] from an [`IntoIterator`].
+///
+/// # Errors
+///
+/// This implementation will return a [`CollectError`] with an
+/// [`Underflow`](crate::errors::capacity::CapacityErrorKind::Underflow) [`CapacityError`]
+/// if the iterator produces no items.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryFromIterator;
+/// let items = nonempty::NonEmpty::try_from_iter(1..=3).expect("should not be empty");
+/// assert_eq!(items, nonempty::nonempty![1, 2, 3]);
+///
+/// nonempty::NonEmpty::<i32>::try_from_iter((1..=3).filter(|_| false)).expect_err("should be empty");
+/// ```
+impl<T, I> TryFromIterator<I> for nonempty::NonEmpty<T>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = CollectError<I::IntoIter, Vec<T>, CapacityError<T>>;
+
+    fn try_from_iter(into_iter: I) -> Result<Self, Self::Error> {
+        NonEmpty::<Vec<T>>::try_from_iter(into_iter)
+            .map_err(|err| err.map_error(WrapperError::into_wrapper))
+            .map(|vec| Self::from_vec(vec.into_inner()).expect("vec should not be empty"))
+    }
+}
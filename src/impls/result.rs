@@ -15,9 +15,9 @@ use crate::errors::types::SizeHint;
 pub struct ResultIter<I: Iterator, E>(
     [Rc<RefCell<IterState<I, E>>>; {
         enum IterState<Iter, E> {
-            Active(Iter),
+            Active(Iter, usize),
             Taken,
-            Errored { error: E, remaining: Iter },
+            Errored { error: E, remaining: Iter, position: usize },
         }
     }],
 );
@@ -30,7 +30,7 @@ where
 
     /// Creates a new `ExtractErr` wrapping the given iterator.
     fn new(iter: I) -> Self {
-        IterState::Active(iter).pipe(RefCell::new).pipe(Rc::new).pipe(ResultIter)
+        IterState::Active(iter, 0).pipe(RefCell::new).pipe(Rc::new).pipe(ResultIter)
     }
 
     /// Takes and returns the inner state, replacing it with an empty state.
@@ -48,15 +48,17 @@ where
 impl<Iter: Iterator, E> IterState<Iter, E> {
     /// Advances the iterator, returning the next value if available, or an error if encountered.
     /// while also updating the state to reflect the result of the advance.
+    ///
+    /// An active state counts the [`Ok`] values yielded, which is the position of any [`Err`].
     fn advance<T>(self) -> (Self, Option<T>)
     where
         Iter: Iterator<Item = Result<T, E>>,
     {
         match self {
-            Self::Active(mut iter) => match iter.next() {
-                Some(Ok(v)) => (Self::Active(iter), Some(v)),
-                Some(Err(e)) => (Self::Errored { error: e, remaining: iter }, None),
-                None => (Self::Active(iter), None),
+            Self::Active(mut iter, position) => match iter.next() {
+                Some(Ok(v)) => (Self::Active(iter, position + 1), Some(v)),
+                Some(Err(e)) => (Self::Errored { error: e, remaining: iter, position }, None),
+                None => (Self::Active(iter, position), None),
             },
             state @ (Self::Errored { .. } | Self::Taken) => (state, None),
         }
@@ -82,7 +84,7 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         match &*self.0.borrow() {
             IterState::Errored { .. } | IterState::Taken => SizeHint::ZERO.into(),
-            IterState::Active(iter) => (0, iter.size_hint().1),
+            IterState::Active(iter, _) => (0, iter.size_hint().1),
         }
     }
 }
@@ -240,9 +242,11 @@ where
         let try_from_result = extractor.share().pipe(C::try_from_iter);
 
         match (extractor.take_inner(), try_from_result) {
-            (IterState::Active(_), Ok(v)) => Ok(Ok(v)),
-            (IterState::Active(_), Err(e)) => Ok(Err(e)),
-            (IterState::Errored { error, remaining }, result) => Err(ResultCollectError::new(error, result, remaining)),
+            (IterState::Active(..), Ok(v)) => Ok(Ok(v)),
+            (IterState::Active(..), Err(e)) => Ok(Err(e)),
+            (IterState::Errored { error, remaining, position }, result) => {
+                Err(ResultCollectError::new(error, result, remaining).with_position(position))
+            }
             (IterState::Taken, _) => unreachable!("take_inner called multiple times"),
         }
     }
@@ -292,22 +296,22 @@ mod iter_state_advance {
     }
 
     test!(advance_active_yields_ok:
-        IterState::Active([Ok::<_, &str>(1)].into_iter())
-        => value: Some(1), state: IterState::Active(_)
+        IterState::Active([Ok::<_, &str>(1)].into_iter(), 0)
+        => value: Some(1), state: IterState::Active(_, 1)
     );
 
     test!(advance_active_yields_err:
-        IterState::Active([Err::<i32, &str>("error")].into_iter())
-        => value: None, state: IterState::Errored { error: "error", .. }
+        IterState::Active([Err::<i32, &str>("error")].into_iter(), 2)
+        => value: None, state: IterState::Errored { error: "error", position: 2, .. }
     );
 
     test!(advance_active_exhausted:
-        IterState::Active(core::iter::empty::<Result<i32, &str>>())
-        => value: None, state: IterState::Active(_)
+        IterState::Active(core::iter::empty::<Result<i32, &str>>(), 0)
+        => value: None, state: IterState::Active(_, 0)
     );
 
     test!(advance_errored_stays_errored:
-        IterState::Errored { error: "error", remaining: [Ok(1), Ok(2)].into_iter() }
+        IterState::Errored { error: "error", remaining: [Ok(1), Ok(2)].into_iter(), position: 0 }
         => value: None, state: IterState::Errored { error: "error", .. }
     );
 
@@ -1,5 +1,8 @@
 use alloc::collections::BTreeSet;
-use fluent_result::bool::dbg::Expect;
+
+use crate::errors::{CollectError, SortedError};
+use crate::impls::sorted::try_collect_sorted;
+use crate::{TryExtendSorted, TryFromSortedIterator};
 
 crate::impls::macros::impl_try_from_iter_via_try_extend_one!(
     type: BTreeSet<T> where [T: Ord] of T;
@@ -14,11 +17,86 @@ crate::impls::macros::impl_try_extend_via_try_extend_one!(
 crate::impls::macros::impl_try_extend_safe_for_colliding_type!(
     type: BTreeSet<T> where [T: Ord] of T;
     build_staging: |_, _| BTreeSet::new();
-    contains: BTreeSet::contains
+    contains: BTreeSet::contains;
+    merge: |set, mut staging| set.append(&mut staging)
+);
+
+crate::impls::macros::impl_try_extend_in_place_via_undo_log!(
+    type: BTreeSet<T> where [T: Ord + Clone] of T;
+    reserve: |_, _| ();
+    empty: |_| BTreeSet::new();
+    key: T::clone;
+    remove: |set, item| set.take(&item)
 );
 
-crate::impls::macros::impl_try_extend_one_for_colliding_type!(
+crate::impls::macros::impl_try_extend_cloned_for_colliding_type!(
+    type: BTreeSet<T> where ['a, T: Ord + Clone + 'a] of &'a T;
+    reserve: |_, _| ();
+    contains: |set, item| set.contains(*item);
+    insert: |set, item| _ = set.insert(item.clone());
+    remove: |set, item| _ = set.remove(item)
+);
+
+crate::impls::macros::impl_try_extend_one_via_replace!(
     type: BTreeSet<T> where [T: Ord] of T;
-    contains: BTreeSet::contains;
-    insert: |set, item| set.insert(item).expect_true("insert should succeed after contains check")
+    replace: BTreeSet::replace
 );
+
+/// Create a [`BTreeSet`] from an iterator of sorted values, failing if a value collides
+/// with, or is less than, the previous value.
+///
+/// The set is built in bulk, without a lookup per item.
+impl<T: Ord, I> TryFromSortedIterator<I> for BTreeSet<T>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = CollectError<I::IntoIter, Self, SortedError<T>>;
+
+    /// Create a [`BTreeSet`] from `into_iter`, whose values must be strictly increasing.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] with a [`SortedError`] if a value collides with, or is less
+    /// than, the previous value. All items from `into_iter` are preserved in the error, and can
+    /// be retrieved using [`CollectError::into_iter`].
+    fn try_from_sorted_iter(into_iter: I) -> Result<Self, Self::Error> {
+        let mut iter = into_iter.into_iter();
+
+        try_collect_sorted(&mut iter, T::cmp, |_| false)
+            .map(Self::from_iter)
+            .map_err(|(sorted, error)| {
+                let position = sorted.len();
+                CollectError::new(iter, Self::from_iter(sorted), error).with_position(position)
+            })
+    }
+}
+
+/// Extend a [`BTreeSet`] with sorted values, failing if a value collides with the set,
+/// or collides with, or is less than, the previous value.
+///
+/// The values are staged in bulk, and then merged into the set via [`BTreeSet::append`].
+impl<T: Ord, I> TryExtendSorted<I> for BTreeSet<T>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = CollectError<I::IntoIter, Self, SortedError<T>>;
+
+    /// Extend the set with `iter`, whose values must be strictly increasing, providing a
+    /// **strong error guarantee**.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] with a [`SortedError`] if a value collides with the set,
+    /// or collides with, or is less than, the previous value. The set is unchanged, and all
+    /// items from `iter` are preserved in the error.
+    fn try_extend_sorted(&mut self, iter: I) -> Result<(), Self::Error> {
+        let mut iter = iter.into_iter();
+
+        try_collect_sorted(&mut iter, T::cmp, |item| self.contains(item))
+            .map(|sorted| self.append(&mut Self::from_iter(sorted)))
+            .map_err(|(sorted, error)| {
+                let position = sorted.len();
+                CollectError::new(iter, Self::from_iter(sorted), error).with_position(position)
+            })
+    }
+}
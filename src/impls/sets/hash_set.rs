@@ -1,26 +1,54 @@
 use core::hash::{BuildHasher, Hash};
 use std::collections::HashSet;
 
-use fluent_result::bool::dbg::Expect;
+use crate::TryReserve;
 
 crate::impls::macros::impl_try_from_iter_via_try_extend_one!(
     type: HashSet<T, S> where [T: Eq + Hash, S: BuildHasher + Default] of T;
-    ctor: |iter| HashSet::with_capacity_and_hasher(iter.size_hint().0, S::default())
+    ctor: |iter| HashSet::with_capacity_and_hasher(crate::prealloc::hint(iter), S::default())
 );
 
 crate::impls::macros::impl_try_extend_via_try_extend_one!(
     type: HashSet<T, S> where [T: Eq + Hash, S: BuildHasher + Clone] of T;
-    reserve: |set, iter| set.reserve(iter.size_hint().0)
+    reserve: |set, iter| set.reserve(crate::prealloc::hint(iter))
 );
 
 crate::impls::macros::impl_try_extend_safe_for_colliding_type!(
     type: HashSet<T, S> where [T: Eq + Hash, S: BuildHasher + Clone] of T;
-    build_staging: |iter, set| HashSet::with_capacity_and_hasher(iter.size_hint().0, set.hasher().clone());
+    build_staging: |iter, set| HashSet::with_capacity_and_hasher(crate::prealloc::hint(iter), set.hasher().clone());
     contains: HashSet::contains
 );
 
-crate::impls::macros::impl_try_extend_one_for_colliding_type!(
+crate::impls::macros::impl_try_extend_in_place_via_undo_log!(
+    type: HashSet<T, S> where [T: Eq + Hash + Clone, S: BuildHasher + Clone] of T;
+    reserve: |set, iter| set.reserve(crate::prealloc::hint(iter));
+    empty: |set| HashSet::with_hasher(set.hasher().clone());
+    key: T::clone;
+    remove: |set, item| set.take(&item)
+);
+
+crate::impls::macros::impl_try_extend_cloned_for_colliding_type!(
+    type: HashSet<T, S> where ['a, T: Eq + Hash + Clone + 'a, S: BuildHasher] of &'a T;
+    reserve: |set, iter| set.reserve(crate::prealloc::hint(iter));
+    contains: |set, item| set.contains(*item);
+    insert: |set, item| _ = set.insert(item.clone());
+    remove: |set, item| _ = set.remove(item)
+);
+
+crate::impls::macros::impl_try_extend_one_via_replace!(
     type: HashSet<T, S> where [T: Eq + Hash, S: BuildHasher] of T;
-    contains: HashSet::contains;
-    insert: |set, item| set.insert(item).expect_true("insert should succeed after contains check")
+    replace: HashSet::replace
 );
+
+impl<T, S> TryReserve for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = std::collections::TryReserveError;
+
+    /// Forwards directly to [`HashSet::try_reserve`].
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        Self::try_reserve(self, additional)
+    }
+}
@@ -0,0 +1,59 @@
+use core::hash::{BuildHasher, Hash};
+
+use hashlink::LinkedHashSet;
+
+use crate::{InsertionOrder, TryReserve};
+
+crate::impls::macros::impl_try_from_iter_via_try_extend_one!(
+    type: LinkedHashSet<T, S> where [T: Eq + Hash, S: BuildHasher + Default] of T;
+    ctor: |iter| LinkedHashSet::with_capacity_and_hasher(crate::prealloc::hint(iter), S::default())
+);
+
+crate::impls::macros::impl_try_extend_via_try_extend_one!(
+    type: LinkedHashSet<T, S> where [T: Eq + Hash, S: BuildHasher + Clone] of T;
+    reserve: |set, iter| set.reserve(crate::prealloc::hint(iter))
+);
+
+crate::impls::macros::impl_try_extend_safe_for_colliding_type!(
+    type: LinkedHashSet<T, S> where [T: Eq + Hash, S: BuildHasher + Clone] of T;
+    build_staging: |iter, set| LinkedHashSet::with_capacity_and_hasher(crate::prealloc::hint(iter), set.hasher().clone());
+    contains: LinkedHashSet::contains
+);
+
+// removal unlinks the item, so the order of the original items is preserved
+crate::impls::macros::impl_try_extend_in_place_via_undo_log!(
+    type: LinkedHashSet<T, S> where [T: Eq + Hash + Clone, S: BuildHasher + Clone] of T;
+    reserve: |set, iter| set.reserve(crate::prealloc::hint(iter));
+    empty: |set| LinkedHashSet::with_hasher(set.hasher().clone());
+    key: T::clone;
+    remove: |set, item| set.take(&item)
+);
+
+crate::impls::macros::impl_try_extend_cloned_for_colliding_type!(
+    type: LinkedHashSet<T, S> where ['a, T: Eq + Hash + Clone + 'a, S: BuildHasher] of &'a T;
+    reserve: |set, iter| set.reserve(crate::prealloc::hint(iter));
+    contains: |set, item| set.contains(*item);
+    insert: |set, item| _ = set.insert(item.clone());
+    remove: |set, item| _ = set.remove(item)
+);
+
+// replacing an item keeps its position, so swapping the original back in leaves the set unchanged
+crate::impls::macros::impl_try_extend_one_via_replace!(
+    type: LinkedHashSet<T, S> where [T: Eq + Hash, S: BuildHasher] of T;
+    replace: LinkedHashSet::replace
+);
+
+impl<T, S> TryReserve for LinkedHashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = hashlink::linked_hash_map::TryReserveError;
+
+    /// Forwards directly to [`LinkedHashSet::try_reserve`].
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        Self::try_reserve(self, additional)
+    }
+}
+
+impl<T, S> InsertionOrder for LinkedHashSet<T, S> {}
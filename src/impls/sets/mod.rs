@@ -9,3 +9,9 @@ pub mod hashbrown_set;
 
 #[cfg(feature = "indexmap")]
 pub mod indexset;
+
+#[cfg(feature = "hashlink")]
+pub mod hashlink_set;
+
+#[cfg(feature = "ordermap")]
+pub mod orderset;
@@ -0,0 +1,56 @@
+use core::hash::{BuildHasher, Hash};
+
+use ordermap::OrderSet;
+
+use crate::{InsertionOrder, TryReserve};
+
+crate::impls::macros::impl_try_from_iter_via_try_extend_one!(
+    type: OrderSet<T, S> where [T: Eq + Hash, S: BuildHasher + Default] of T;
+    ctor: |iter| OrderSet::with_capacity_and_hasher(crate::prealloc::hint(iter), S::default())
+);
+
+crate::impls::macros::impl_try_extend_via_try_extend_one!(
+    type: OrderSet<T, S> where [T: Eq + Hash, S: BuildHasher + Clone] of T;
+    reserve: |set, iter| set.reserve(crate::prealloc::hint(iter))
+);
+
+crate::impls::macros::impl_try_extend_safe_for_colliding_type!(
+    type: OrderSet<T, S> where [T: Eq + Hash, S: BuildHasher + Clone] of T;
+    build_staging: |iter, set| OrderSet::with_capacity_and_hasher(crate::prealloc::hint(iter), set.hasher().clone());
+    contains: OrderSet::contains
+);
+
+// swap removal only moves inserted items, so the order of the original items is preserved
+crate::impls::macros::impl_try_extend_in_place_via_undo_log!(
+    type: OrderSet<T, S> where [T: Eq + Hash, S: BuildHasher + Clone] of T;
+    reserve: |set, iter| set.reserve(crate::prealloc::hint(iter));
+    split_off: |set, inserted| set.split_off(set.len() - inserted)
+);
+
+crate::impls::macros::impl_try_extend_cloned_for_colliding_type!(
+    type: OrderSet<T, S> where ['a, T: Eq + Hash + Clone + 'a, S: BuildHasher] of &'a T;
+    reserve: |set, iter| set.reserve(crate::prealloc::hint(iter));
+    contains: |set, item| set.contains(*item);
+    insert: |set, item| _ = set.insert(item.clone());
+    remove: |set, item| _ = set.swap_remove(item)
+);
+
+crate::impls::macros::impl_try_extend_one_via_replace!(
+    type: OrderSet<T, S> where [T: Eq + Hash, S: BuildHasher] of T;
+    replace: OrderSet::replace
+);
+
+impl<T, S> TryReserve for OrderSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = ordermap::TryReserveError;
+
+    /// Forwards directly to [`OrderSet::try_reserve`].
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        Self::try_reserve(self, additional)
+    }
+}
+
+impl<T, S> InsertionOrder for OrderSet<T, S> {}
@@ -0,0 +1,184 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use fluent_result::into::IntoResult;
+use tap::Pipe;
+
+use crate::errors::capacity::{FixedCap, RemainingCap};
+use crate::errors::types::SizeHint;
+use crate::errors::{CapacityError, CollectError};
+use crate::{TryFromIterator, TryFromIteratorWithCap};
+
+/// The error returned when collecting into a heap allocated slice fails.
+type SliceCollectError<I, T> = CollectError<I, Vec<T>, CapacityError<T>>;
+
+/// Collects `iter` into a [`Vec`], failing if it does not produce a number of items within `cap`.
+///
+/// # Panics
+///
+/// Panics if `iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+fn try_collect_vec_with_cap<I: Iterator>(mut iter: I, cap: SizeHint) -> Result<Vec<I::Item>, SliceCollectError<I, I::Item>> {
+    if let Err(error) = CapacityError::ensure_fits(&iter, cap) {
+        return CollectError::new(iter, Vec::new(), error).into_err();
+    }
+
+    // a fixed size target needs exactly its size on success, so allocate it up front, rather than
+    // growing from a hidden size hint and reallocating when the vec is converted
+    let hint = crate::prealloc::hint(&iter);
+    let capacity = match cap.upper() {
+        Some(upper) if upper == cap.lower() => upper,
+        upper => upper.map_or(hint, |upper| upper.min(hint)),
+    };
+    let mut vec = Vec::with_capacity(capacity);
+    vec.extend(iter.by_ref().take(cap.upper().unwrap_or(usize::MAX)));
+
+    // only a bounded capacity can overflow
+    match cap.upper().and_then(|_| iter.next()) {
+        Some(overflow) => {
+            let position = vec.len();
+            CollectError::overflow(iter, vec, overflow, cap).with_position(position).into_err()
+        }
+        None if vec.len() < cap.lower() => {
+            CapacityError::underflow(cap, vec.len()).pipe(|error| CollectError::new(iter, vec, error)).into_err()
+        }
+        None => Ok(vec),
+    }
+}
+
+impl<const N: usize, T> RemainingCap for Box<[T; N]> {
+    /// Always returns [`SizeHint::ZERO`].
+    fn remaining_cap(&self) -> SizeHint {
+        SizeHint::ZERO
+    }
+}
+
+impl<const N: usize, T> FixedCap for Box<[T; N]> {
+    /// Always returns [`SizeHint::exact(N)`](SizeHint::exact).
+    const CAP: SizeHint = SizeHint::exact(N);
+}
+
+/// Create a boxed array of size `N` from an iterator, failing if the iterator
+/// does not produce exactly `N` items.
+///
+/// Unlike the `[T; N]` implementation, items are collected directly on the heap,
+/// so large arrays can be collected without risking a stack overflow.
+impl<const N: usize, T, I> TryFromIterator<I> for Box<[T; N]>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = SliceCollectError<I::IntoIter, T>;
+
+    /// Create a boxed array from `iter`, failing if `iter` does not produce exactly `N` items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectError`] if `iter` does not produce exactly `N` items.
+    /// All items from `iter` are preserved in the error, and can be retrieved using
+    /// [`CollectError::into_iter`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use collect_failable::TryFromIterator;
+    ///
+    /// let array = Box::<[_; 3]>::try_from_iter(1..=3).expect("should succeed");
+    /// assert_eq!(*array, [1, 2, 3]);
+    ///
+    /// let too_few_err = Box::<[u32; 3]>::try_from_iter(1..=2).expect_err("should fail, too few items");
+    /// assert_eq!(too_few_err.into_iter().collect::<Vec<_>>(), vec![1, 2], "err should contain all items");
+    /// ```
+    fn try_from_iter(into_iter: I) -> Result<Self, Self::Error> {
+        try_collect_vec_with_cap(into_iter.into_iter(), Self::CAP)
+            .map(|vec| vec.into_boxed_slice().try_into().unwrap_or_else(|_| unreachable!("slice should have exactly N items")))
+    }
+}
+
+/// Create a boxed slice from an iterator, failing if the iterator does not
+/// produce a number of items within the given capacity.
+impl<T, I> TryFromIteratorWithCap<I> for Box<[T]>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = SliceCollectError<I::IntoIter, T>;
+
+    /// Create a boxed slice from `iter`, failing if `iter` does not produce a
+    /// number of items within `cap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectError`] if `iter` does not produce a number of items within
+    /// `cap`. All items from `iter` are preserved in the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use collect_failable::TryFromIteratorWithCap;
+    /// use collect_failable::errors::types::SizeHint;
+    ///
+    /// let slice = Box::<[_]>::try_from_iter_with_cap(1..=3, SizeHint::exact(3)).expect("should succeed");
+    /// assert_eq!(*slice, [1, 2, 3]);
+    /// ```
+    fn try_from_iter_with_cap(into_iter: I, cap: SizeHint) -> Result<Self, Self::Error> {
+        try_collect_vec_with_cap(into_iter.into_iter(), cap).map(Vec::into_boxed_slice)
+    }
+}
+
+/// Create a reference-counted slice from an iterator, failing if the iterator
+/// does not produce a number of items within the given capacity.
+impl<T, I> TryFromIteratorWithCap<I> for Rc<[T]>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = SliceCollectError<I::IntoIter, T>;
+
+    /// Create a reference-counted slice from `iter`, failing if `iter` does not
+    /// produce a number of items within `cap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectError`] if `iter` does not produce a number of items within
+    /// `cap`. All items from `iter` are preserved in the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+    fn try_from_iter_with_cap(into_iter: I, cap: SizeHint) -> Result<Self, Self::Error> {
+        try_collect_vec_with_cap(into_iter.into_iter(), cap).map(Self::from)
+    }
+}
+
+/// Create an atomically reference-counted slice from an iterator, failing if the
+/// iterator does not produce a number of items within the given capacity.
+#[cfg(target_has_atomic = "ptr")]
+impl<T, I> TryFromIteratorWithCap<I> for Arc<[T]>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = SliceCollectError<I::IntoIter, T>;
+
+    /// Create an atomically reference-counted slice from `iter`, failing if `iter`
+    /// does not produce a number of items within `cap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectError`] if `iter` does not produce a number of items within
+    /// `cap`. All items from `iter` are preserved in the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `iter`'s [`size_hint`](Iterator::size_hint) is invalid.
+    fn try_from_iter_with_cap(into_iter: I, cap: SizeHint) -> Result<Self, Self::Error> {
+        try_collect_vec_with_cap(into_iter.into_iter(), cap).map(Self::from)
+    }
+}
@@ -0,0 +1,34 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::errors::sorted::{SortedError, SortedErrorKind};
+
+/// The items collected before an item was rejected, and the rejected item.
+type SortedFailure<T> = (Vec<T>, SortedError<T>);
+
+/// Collects `iter` into a [`Vec`], ensuring the items are strictly increasing according to `cmp`,
+/// and that `contains` returns `false` for every item.
+///
+/// On failure, returns the items collected so far and the rejected item. `iter` is left
+/// positioned after the rejected item.
+pub fn try_collect_sorted<I, F, C>(iter: &mut I, cmp: F, contains: C) -> Result<Vec<I::Item>, SortedFailure<I::Item>>
+where
+    I: Iterator,
+    F: Fn(&I::Item, &I::Item) -> Ordering,
+    C: Fn(&I::Item) -> bool,
+{
+    let check = |last: Option<&I::Item>, item: &I::Item| match last.map(|last| cmp(last, item)) {
+        Some(Ordering::Greater) => Some(SortedErrorKind::NotSorted),
+        Some(Ordering::Equal) => Some(SortedErrorKind::Collision),
+        _ if contains(item) => Some(SortedErrorKind::Collision),
+        _ => None,
+    };
+
+    iter.try_fold(Vec::with_capacity(crate::prealloc::hint(&iter)), |mut sorted, item| match check(sorted.last(), &item) {
+        Some(kind) => Err((sorted, SortedError::new(item, kind))),
+        None => {
+            sorted.push(item);
+            Ok(sorted)
+        }
+    })
+}
@@ -52,12 +52,12 @@ where
     /// ```
     fn try_extend(&mut self, iter: I) -> Result<(), Self::Error> {
         let mut iter = iter.into_iter();
-        for (a, b) in iter.by_ref() {
+        for (position, (a, b)) in iter.by_ref().enumerate() {
             if let Err(error) = self.0.try_extend_one(a) {
-                return TupleExtendError::new(error, Some(b), iter).pipe(Either::Left).into_err();
+                return TupleExtendError::new(error, Some(b), iter).with_position(position).pipe(Either::Left).into_err();
             }
             if let Err(error) = self.1.try_extend_one(b) {
-                return TupleExtendError::new(error, None, iter).pipe::<Self::Error>(Either::Right).into_err();
+                return TupleExtendError::new(error, None, iter).with_position(position).pipe::<Self::Error>(Either::Right).into_err();
             }
         }
 
@@ -1,7 +1,12 @@
+use core::convert::Infallible;
+
 use crate::errors::capacity::{FixedCap, RemainingCap};
 use crate::errors::types::SizeHint;
+use alloc::collections::TryReserveError;
 use alloc::vec::Vec;
 
+use crate::{InsertionOrder, TryExtend, TryExtendCloned, TryExtendClonedSafe, TryExtendOne, TryExtendSafe, TryReserve};
+
 impl<T> RemainingCap for Vec<T> {
     /// Returns [`SizeHint::unbounded(0)`](SizeHint::unbounded) because [`Vec`]
     /// can grow indefinitely.
@@ -15,3 +20,65 @@ impl<T> FixedCap for Vec<T> {
     /// has no fixed capacity.
     const CAP: SizeHint = SizeHint::unbounded(0);
 }
+
+impl<T> TryReserve for Vec<T> {
+    type Error = TryReserveError;
+
+    /// Forwards directly to [`Vec::try_reserve`].
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error> {
+        Self::try_reserve(self, additional)
+    }
+}
+
+impl<T> TryExtendOne for Vec<T> {
+    type Item = T;
+    type Error = Infallible;
+
+    /// Pushes `item`, which can not fail.
+    fn try_extend_one(&mut self, item: T) -> Result<(), Self::Error> {
+        self.push(item);
+        Ok(())
+    }
+}
+
+impl<T, I: IntoIterator<Item = T>> TryExtend<I> for Vec<T> {
+    type Error = Infallible;
+
+    /// Extends the [`Vec`] with `iter`, which can not fail.
+    fn try_extend(&mut self, iter: I) -> Result<(), Self::Error> {
+        self.extend(iter);
+        Ok(())
+    }
+}
+
+impl<T, I: IntoIterator<Item = T>> TryExtendSafe<I> for Vec<T> {
+    type Error = Infallible;
+
+    /// Extends the [`Vec`] with `iter`, which can not fail.
+    fn try_extend_safe(&mut self, iter: I) -> Result<(), Self::Error> {
+        self.extend(iter);
+        Ok(())
+    }
+}
+
+impl<'a, T: Clone + 'a, I: IntoIterator<Item = &'a T>> TryExtendCloned<I> for Vec<T> {
+    type Error = Infallible;
+
+    /// Extends the [`Vec`] with clones of `iter`'s items, which can not fail.
+    fn try_extend_cloned(&mut self, iter: I) -> Result<(), Self::Error> {
+        self.extend(iter.into_iter().cloned());
+        Ok(())
+    }
+}
+
+impl<'a, T: Clone + 'a, I: IntoIterator<Item = &'a T>> TryExtendClonedSafe<I> for Vec<T> {
+    type Error = Infallible;
+
+    /// Extends the [`Vec`] with clones of `iter`'s items, which can not fail.
+    fn try_extend_cloned_safe(&mut self, iter: I) -> Result<(), Self::Error> {
+        self.extend(iter.into_iter().cloned());
+        Ok(())
+    }
+}
+
+impl<T> InsertionOrder for Vec<T> {}
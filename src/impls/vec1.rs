@@ -0,0 +1,38 @@
+use alloc::vec::Vec;
+
+use vec1::Vec1;
+
+use crate::TryFromIterator;
+use crate::collections::NonEmpty;
+use crate::errors::{CapacityError, CollectError, WrapperError};
+
+/// Tries to create a [`Vec1`] from an [`IntoIterator`].
+///
+/// # Errors
+///
+/// This implementation will return a [`CollectError`] with an
+/// [`Underflow`](crate::errors::capacity::CapacityErrorKind::Underflow) [`CapacityError`]
+/// if the iterator produces no items.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::TryFromIterator;
+/// # use vec1::{Vec1, vec1};
+/// let items = Vec1::try_from_iter(1..=3).expect("should not be empty");
+/// assert_eq!(items, vec1![1, 2, 3]);
+///
+/// Vec1::<i32>::try_from_iter((1..=3).filter(|_| false)).expect_err("should be empty");
+/// ```
+impl<T, I> TryFromIterator<I> for Vec1<T>
+where
+    I: IntoIterator<Item = T>,
+{
+    type Error = CollectError<I::IntoIter, Vec<T>, CapacityError<T>>;
+
+    fn try_from_iter(into_iter: I) -> Result<Self, Self::Error> {
+        NonEmpty::<Vec<T>>::try_from_iter(into_iter)
+            .map_err(|err| err.map_error(WrapperError::into_wrapper))
+            .map(|vec| Self::try_from_vec(vec.into_inner()).expect("vec should not be empty"))
+    }
+}
@@ -0,0 +1,192 @@
+//! Iterator adapters for use with failable collection.
+
+use core::iter::FusedIterator;
+
+use crate::errors::size_hint::SizeHintViolation;
+use crate::errors::{CapacityError, CollectError, CollectErrorData, ErrorItemProvider, ExtendError, ExtendErrorData};
+
+/// An [`Iterator`] adapter that checks the wrapped iterator honors its
+/// [`size_hint`](Iterator::size_hint).
+///
+/// The hint of the wrapped iterator is recorded when the adapter is created. All items are
+/// passed through unchanged, and the first violation of the recorded hint is kept as a
+/// [`SizeHintViolation`], retrievable with [`violation`](CheckedSizeHint::violation) or
+/// [`check`](CheckedSizeHint::check).
+///
+/// The adapter's own [`size_hint`](Iterator::size_hint) is always valid. It is derived from the
+/// recorded hint, and becomes `(0, None)` once a violation is detected, so collections relying
+/// on it will not panic.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::iter::CheckedSizeHint;
+/// # use collect_failable::errors::size_hint::SizeHintViolationKind;
+/// # use size_hinter::SizeHinter;
+/// let mut iter = CheckedSizeHint::new((1..=3).hide_size().hint_size(4, 5));
+///
+/// assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 2, 3], "items should be unchanged");
+///
+/// let err = iter.check().expect_err("should underflow");
+/// assert_eq!(err.error.kind, SizeHintViolationKind::Underflow { count: 3 });
+/// ```
+#[derive(Debug, Clone)]
+pub struct CheckedSizeHint<I> {
+    iter: I,
+    hint: (usize, Option<usize>),
+    count: usize,
+    violation: Option<SizeHintViolation>,
+}
+
+impl<I: Iterator> CheckedSizeHint<I> {
+    /// Wraps `iter`, recording its current [`size_hint`](Iterator::size_hint).
+    ///
+    /// If the hint is invalid, the [`Invalid`](crate::errors::size_hint::SizeHintViolationKind::Invalid)
+    /// violation is recorded immediately.
+    pub fn new(iter: I) -> Self {
+        let hint = iter.size_hint();
+        let violation = SizeHintViolation::validate(hint).err();
+
+        Self { iter, hint, count: 0, violation }
+    }
+
+    /// Returns the first violation of the recorded hint, if any.
+    #[must_use]
+    pub const fn violation(&self) -> Option<SizeHintViolation> {
+        self.violation
+    }
+
+    /// Returns the number of items produced so far.
+    #[must_use]
+    pub const fn produced(&self) -> usize {
+        self.count
+    }
+
+    /// Unwraps the adapter, returning the wrapped iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Unwraps the adapter, failing if a violation was detected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExtendError`] containing the wrapped iterator and the first
+    /// [`SizeHintViolation`], if one was detected.
+    pub fn check(self) -> Result<I, ExtendError<I, SizeHintViolation>> {
+        match self.violation {
+            Some(violation) => Err(ExtendError::new(self.iter, violation)),
+            None => Ok(self.iter),
+        }
+    }
+
+    /// Replaces `error` with a [`SizeHint`](crate::errors::capacity::CapacityErrorKind::SizeHint)
+    /// error if a violation was detected, unless `error` holds an item that would otherwise be lost.
+    fn strict_error(&self, error: CapacityError<I::Item>) -> CapacityError<I::Item> {
+        match self.violation {
+            Some(violation) if error.item().is_none() => CapacityError::size_hint(error.capacity, violation),
+            _ => error,
+        }
+    }
+
+    /// Converts a [`CollectError`] over this adapter into one over the wrapped iterator,
+    /// reporting any detected violation.
+    pub(crate) fn strict_collect_error<C>(
+        error: CollectError<Self, C, CapacityError<I::Item>>,
+    ) -> CollectError<I, C, CapacityError<I::Item>> {
+        let CollectErrorData { remain, collected, error, position } = error.into_data();
+        let error = remain.strict_error(error);
+
+        CollectError::from_data(CollectErrorData { remain: remain.into_inner(), collected, error, position })
+    }
+
+    /// Converts an [`ExtendError`] over this adapter into one over the wrapped iterator,
+    /// reporting any detected violation.
+    pub(crate) fn strict_extend_error(
+        error: ExtendError<Self, CapacityError<I::Item>>,
+    ) -> ExtendError<I, CapacityError<I::Item>> {
+        let ExtendErrorData { remain, error, position } = error.into_data();
+        let error = remain.strict_error(error);
+
+        ExtendError::from_data(ExtendErrorData { remain: remain.into_inner(), error, position })
+    }
+}
+
+impl<I: Iterator> Iterator for CheckedSizeHint<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+
+        if item.is_some() {
+            self.count += 1;
+        }
+
+        if self.violation.is_none() {
+            self.violation = match (&item, self.hint) {
+                (Some(_), (_, Some(upper))) if self.count > upper => Some(SizeHintViolation::overflow(self.hint, self.count)),
+                (None, (lower, _)) if self.count < lower => Some(SizeHintViolation::underflow(self.hint, self.count)),
+                _ => None,
+            };
+        }
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.violation {
+            Some(_) => (0, None),
+            None => (self.hint.0.saturating_sub(self.count), self.hint.1.map(|upper| upper - self.count)),
+        }
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for CheckedSizeHint<I> {}
+
+/// An [`Iterator`] adapter that limits the lower bound of the wrapped iterator's
+/// [`size_hint`](Iterator::size_hint), bounding how much collections pre-allocate.
+///
+/// This is the per call equivalent of [`prealloc::set_max`](crate::prealloc::set_max). Items
+/// are passed through unchanged, and the upper bound is not changed, so the hint stays valid.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::iter::LimitPrealloc;
+/// let iter = LimitPrealloc::new(0..100, 10);
+///
+/// assert_eq!(iter.size_hint(), (10, Some(100)));
+/// assert_eq!(iter.count(), 100, "all items should be produced");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LimitPrealloc<I> {
+    iter: I,
+    max: usize,
+}
+
+impl<I: Iterator> LimitPrealloc<I> {
+    /// Wraps `iter`, limiting the lower bound of its [`size_hint`](Iterator::size_hint) to `max`.
+    pub const fn new(iter: I, max: usize) -> Self {
+        Self { iter, max }
+    }
+
+    /// Unwraps the adapter, returning the wrapped iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Iterator for LimitPrealloc<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower.min(self.max), upper)
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for LimitPrealloc<I> {}
@@ -3,6 +3,7 @@
 #![warn(missing_docs, missing_debug_implementations)]
 #![allow(clippy::match_bool, clippy::single_match_else)]
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "unsafe"), forbid(unsafe_code))]
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -10,9 +11,15 @@ extern crate std;
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "alloc")]
+pub mod collections;
 pub mod errors;
 #[doc(hidden)]
 pub mod impls;
+pub mod iter;
+pub mod prealloc;
 mod traits;
+mod transaction;
 
 pub use traits::*;
+pub use transaction::{Transaction, Transactional};
@@ -0,0 +1,89 @@
+//! Limits on how much collections pre-allocate from an iterator's [`size_hint`](Iterator::size_hint).
+//!
+//! Collections in this crate reserve space for the lower bound of an iterator's
+//! [`size_hint`](Iterator::size_hint) before iterating. An iterator over untrusted input may
+//! report an arbitrarily large lower bound, causing a huge allocation before the first error is
+//! found. All such pre-allocation is limited to [`max`], which can be lowered globally with
+//! [`set_max`], or per call by wrapping the iterator in a [`LimitPrealloc`].
+//!
+//! To report an allocation failure instead of aborting, reserve space with
+//! [`TryCollectEx::try_reserve_into`](crate::TryCollectEx::try_reserve_into) before collecting.
+//!
+//! # No Limit by Default
+//!
+//! [`max`] defaults to [`usize::MAX`], which offers **no protection**: as with
+//! [`Iterator::collect`], the lower bound of every size hint is trusted until a limit is set.
+//! Code collecting untrusted input should set a limit, or wrap the iterator in a
+//! [`LimitPrealloc`].
+//!
+//! # Global Setting
+//!
+//! The limit set by [`set_max`] is a single value shared by the whole process, including every
+//! thread and every library that uses this crate. Set it once, early in `main`, rather than
+//! changing it around individual calls, and prefer [`LimitPrealloc`] to limit a single call. The
+//! limit is read with relaxed ordering, so a change is not guaranteed to be observed by a
+//! collection already running on another thread.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use collect_failable::TryCollectEx;
+//! # use collect_failable::iter::LimitPrealloc;
+//! # use size_hinter::SizeHinter;
+//! # use std::collections::HashSet;
+//! // an iterator claiming far more items than it produces
+//! let iter = (1..=3).hide_size().hint_min(usize::MAX / 2);
+//!
+//! let set: HashSet<_> = LimitPrealloc::new(iter, 16).try_collect_ex().expect("should not collide");
+//! assert_eq!(set, HashSet::from([1, 2, 3]));
+//! ```
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(doc)]
+use crate::iter::LimitPrealloc;
+
+static MAX_PREALLOC: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Returns the maximum number of items collections will pre-allocate space for.
+///
+/// Defaults to [`usize::MAX`], so the lower bound of an iterator's
+/// [`size_hint`](Iterator::size_hint) is trusted, and there is no protection against an
+/// oversized hint. See the [module documentation](self).
+#[must_use]
+pub fn max() -> usize {
+    MAX_PREALLOC.load(Ordering::Relaxed)
+}
+
+/// Sets the maximum number of items collections will pre-allocate space for.
+///
+/// This applies to all collections, on all threads, for the rest of the process. Collections still
+/// grow as needed past this limit as items are produced. See the [module documentation](self) for
+/// when to call it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::prealloc;
+/// prealloc::set_max(1024);
+/// assert_eq!(prealloc::max(), 1024);
+/// # prealloc::set_max(usize::MAX);
+/// ```
+pub fn set_max(max: usize) {
+    MAX_PREALLOC.store(max, Ordering::Relaxed);
+}
+
+/// Returns the number of items to pre-allocate space for when collecting `iter`.
+///
+/// This is the lower bound of `iter`'s [`size_hint`](Iterator::size_hint), limited to [`max`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::prealloc;
+/// assert_eq!(prealloc::hint(&(0..10)), 10);
+/// ```
+#[must_use]
+pub fn hint<I: Iterator + ?Sized>(iter: &I) -> usize {
+    iter.size_hint().0.min(max())
+}
@@ -0,0 +1,16 @@
+#[cfg(doc)]
+use crate::errors::UnzipError;
+
+/// Marker trait for collections that iterate over their items in the order they were added.
+///
+/// Recovery methods that zip two collections back into pairs, such as
+/// [`UnzipError::into_left_iter`], require both collections implement this trait, as the
+/// original pairs can only be rebuilt if each collection yields its items in insertion order.
+///
+/// Implemented for `Vec`, `ArrayVec`, `IndexMap`, `IndexSet`, `OrderMap`, `OrderSet`,
+/// `LinkedHashMap` and `LinkedHashSet`. [`SortedVec`](crate::collections::SortedVec) and
+/// [`SortedSet`](crate::collections::SortedSet) also implement it, as they reject any item that
+/// would be ordered before an existing item, and [`MinLen`](crate::collections::MinLen) and
+/// [`Validated`](crate::collections::Validated) do when their inner collection does. Collections
+/// ordered by hash or by value, such as `HashSet` or `BTreeSet`, do not.
+pub trait InsertionOrder {}
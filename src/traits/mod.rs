@@ -1,11 +1,19 @@
+mod insertion_order;
 mod try_extend;
 mod try_from_iterator;
+mod try_from_sorted_iterator;
+#[cfg(feature = "alloc")]
+mod try_reserve;
 
 #[cfg(feature = "tuples")]
 mod try_unzip;
 
+pub use insertion_order::InsertionOrder;
 pub use try_extend::*;
 pub use try_from_iterator::*;
+pub use try_from_sorted_iterator::*;
+#[cfg(feature = "alloc")]
+pub use try_reserve::TryReserve;
 
 #[cfg(feature = "tuples")]
 pub use try_unzip::TryUnzip;
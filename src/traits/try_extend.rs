@@ -1,5 +1,5 @@
 #[cfg(doc)]
-use crate::TryFromIterator;
+use crate::{TryCollectEx, TryFromIterator};
 #[cfg(doc)]
 use std::collections::HashMap;
 
@@ -17,6 +17,19 @@ use std::collections::HashMap;
 /// elements in the iterator in order to optimize their implementations. An iterator that violates
 /// the bounds returned by [`Iterator::size_hint`] may cause panics, produce incorrect results, or
 /// produce a result that violates container constraints, but must not result in undefined behavior.
+/// For fixed capacity containers, [`TryCollectEx::try_collect_into_strict`] reports such iterators
+/// as an error instead.
+///
+/// # Borrowed Items
+///
+/// Unlike [`Extend`], which is generic over the item type, this trait is generic over the
+/// [`IntoIterator`] type. Implementations for owned items (`I: IntoIterator<Item = T>`) and
+/// borrowed items (`I: IntoIterator<Item = &T>`) on the same collection would therefore
+/// overlap, and are rejected by the compiler. The same applies to [`TryFromIterator`],
+/// [`TryExtendSafe`], and [`TryExtendOne`], whose item type is an associated type.
+///
+/// To extend a collection from borrowed items, see [`TryExtendCloned`], which only clones
+/// the items that are accepted.
 pub trait TryExtend<I: IntoIterator> {
     /// The type of error that can occur during extension.
     type Error;
@@ -99,10 +112,67 @@ pub trait TryExtendSafe<I: IntoIterator> {
     fn try_extend_safe(&mut self, iter: I) -> Result<(), Self::Error>;
 }
 
+/// Trait for extending a collection in place with a **strong error guarantee**.
+///
+/// Like [`TryExtendSafe`], the collection remains unchanged if an error occurs during extension,
+/// and the same error is returned. But rather than staging items in a second collection, and
+/// merging it on success, items are inserted directly and recorded in an undo log. On failure,
+/// the recorded items are removed again.
+///
+/// This avoids building a second collection and re-inserting every item on success, at the cost
+/// of recording each item, and of removing every inserted item on failure. It tends to be faster
+/// when extension is expected to succeed, particularly with many items. [`TryExtendSafe`] tends
+/// to be faster when extension is expected to fail.
+///
+/// # Undo Log
+///
+/// Most implementations log a clone of each inserted key, so require `K: Clone` for maps, or
+/// `T: Clone` for sets, and clone every inserted key even when extension succeeds. With keys
+/// that are expensive to clone, such as [`String`], [`TryExtendSafe`] may be faster.
+///
+/// `IndexMap`, `IndexSet`, `OrderMap` and `OrderSet` append inserted items, so split them off by
+/// index instead, without logging or cloning anything. [`BTreeMap`](std::collections::BTreeMap)
+/// can not log a range of keys instead, since inserted keys may interleave with existing ones.
+pub trait TryExtendInPlace<I: IntoIterator> {
+    /// The type of error that can occur during extension.
+    type Error;
+
+    /// Tries to extend the collection in place, providing a **strong error guarantee**.
+    ///
+    /// On failure, every item inserted by this call is removed, leaving the collection unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryExtendInPlace::Error`] if a failure occurs while extending the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtendInPlace;
+    /// # use std::collections::HashMap;
+    /// let mut map = HashMap::from([(1, 2), (2, 3)]);
+    /// let err = map.try_extend_in_place([(3, 4), (1, 5), (4, 6)]).expect_err("should collide");
+    ///
+    /// assert_eq!(err.error.item, (1, 5), "item should be the colliding item");
+    /// assert_eq!(err.collected, HashMap::from([(3, 4)]), "inserted items should be removed");
+    /// assert_eq!(map, HashMap::from([(1, 2), (2, 3)]), "map should be unchanged");
+    /// ```
+    fn try_extend_in_place(&mut self, iter: I) -> Result<(), Self::Error>;
+}
+
 /// Extension trait providing convenience method for extending a collection with a single item.
 ///
 /// This method provides a **strong error guarantee**: on failure, the collection
 /// remains unchanged.
+///
+/// # Lookups
+///
+/// Sets, `IndexMap` and `hashbrown::HashMap` find an item's slot with a single lookup. Sets do
+/// so via `replace`, so a colliding item costs a second lookup, to swap the original back.
+///
+/// `std` [`HashMap`] and [`BTreeMap`](std::collections::BTreeMap) are out of scope for single
+/// lookup insertion: they check `contains_key` before inserting, since stable `std` has no entry
+/// API that returns the caller's key on a collision.
 pub trait TryExtendOne {
     /// The type of item that can be extended into the collection.
     type Item;
@@ -135,3 +205,92 @@ pub trait TryExtendOne {
     /// ```
     fn try_extend_one(&mut self, item: Self::Item) -> Result<(), Self::Error>;
 }
+
+/// Trait for extending a collection from an iterator of borrowed items, cloning only the items
+/// that are accepted.
+///
+/// This is the borrowed equivalent of [`TryExtend`], providing a **basic error guarantee**.
+/// Each item is checked against the collection before it is cloned, so a rejected item is
+/// returned still borrowed, and is never cloned. See [`TryExtend`] for why this is a separate
+/// trait.
+///
+/// [`TryExtendClonedSafe`] provides a strong error guarantee, and
+/// [`TryCollectEx::try_collect_cloned`] collects into
+/// a new collection.
+///
+/// # Implementations
+///
+/// Sets and maps reject borrowed items that collide with an item in the collection, and
+/// `ArrayVec` rejects the first borrowed item that does not fit. [`Vec`]
+/// accepts every item, so can not fail.
+///
+/// Fixed size arrays are out of scope: an array can not be extended, and collecting one
+/// from borrowed items only rejects the item after the last, so `iter.cloned()` clones at most
+/// one extra item.
+pub trait TryExtendCloned<I: IntoIterator> {
+    /// The type of error that can occur during extension.
+    type Error;
+
+    /// Tries to extend the collection with clones of the borrowed items, providing a **basic
+    /// error guarantee**.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryExtendCloned::Error`] if a failure occurs while extending the collection.
+    ///
+    /// # Examples
+    ///
+    /// The provided [`HashMap`] implementation errors if a key collision occurs, returning the
+    /// colliding borrowed pair.
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtendCloned;
+    /// # use std::collections::HashMap;
+    /// let source = HashMap::from([(String::from("b"), 2), (String::from("a"), 3)]);
+    /// let mut map = HashMap::from([(String::from("a"), 1)]);
+    ///
+    /// let err = map.try_extend_cloned(source.iter().filter(|(key, _)| *key == "a")).expect_err("should collide");
+    /// assert_eq!(err.error.item, (&String::from("a"), &3), "rejected item should be borrowed");
+    /// assert_eq!(map, HashMap::from([(String::from("a"), 1)]), "map should be unchanged");
+    /// ```
+    fn try_extend_cloned(&mut self, iter: I) -> Result<(), Self::Error>;
+}
+
+/// Trait for extending a collection from an iterator of borrowed items, cloning only the items
+/// that are accepted, with a **strong error guarantee**.
+///
+/// This is the borrowed equivalent of [`TryExtendSafe`]. On failure, the collection is
+/// unchanged, and the items that were accepted before the failure are returned still borrowed.
+/// See [`TryExtendCloned`] for the implementations.
+pub trait TryExtendClonedSafe<I: IntoIterator> {
+    /// The type of error that can occur during extension.
+    type Error;
+
+    /// Tries to extend the collection with clones of the borrowed items, providing a **strong
+    /// error guarantee**.
+    ///
+    /// Sets and maps clone accepted items as they are inserted, so on failure those clones are
+    /// dropped when the collection is restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryExtendClonedSafe::Error`] if a failure occurs while extending the collection.
+    ///
+    /// # Examples
+    ///
+    /// The provided [`HashMap`] implementation errors if a key collision occurs, returning the
+    /// colliding borrowed pair and the borrowed pairs that were accepted before it.
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtendClonedSafe;
+    /// # use std::collections::HashMap;
+    /// let source = [(String::from("b"), 2), (String::from("a"), 3)];
+    /// let mut map = HashMap::from([(String::from("a"), 1)]);
+    ///
+    /// let err = map.try_extend_cloned_safe(source.iter().map(|(key, value)| (key, value))).expect_err("should collide");
+    /// assert_eq!(err.error.item, (&String::from("a"), &3), "rejected item should be borrowed");
+    /// assert_eq!(err.collected, [(&String::from("b"), &2)], "accepted items should be borrowed");
+    /// assert_eq!(map, HashMap::from([(String::from("a"), 1)]), "map should be unchanged");
+    /// ```
+    fn try_extend_cloned_safe(&mut self, iter: I) -> Result<(), Self::Error>;
+}
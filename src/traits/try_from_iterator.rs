@@ -1,6 +1,32 @@
 #[cfg(doc)]
+use crate::errors::capacity::CapacityErrorKind;
+#[cfg(doc)]
 use std::collections::HashMap;
 
+use fluent_result::into::IntoResult;
+use tap::Pipe;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "hashbrown")]
+use crate::collections::{HashTableWith, TableCollectError};
+use crate::errors::capacity::{FixedCap, RemainingCap};
+use crate::errors::invalid::{Invalid, InvalidCollectError};
+#[cfg(feature = "alloc")]
+use crate::errors::types::SizeHint;
+use crate::errors::{CapacityError, CollectError, CollectErrorData, ExtendError, ExtendErrorData};
+#[cfg(feature = "alloc")]
+use crate::errors::{ErrorItemProvider, SkippingResult};
+use crate::iter::CheckedSizeHint;
+#[cfg(feature = "alloc")]
+use crate::prealloc;
+use crate::{TryExtend, TryExtendCloned, TryExtendSafe};
+#[cfg(feature = "alloc")]
+use crate::{TryExtendOne, TryReserve};
+#[cfg(feature = "hashbrown")]
+use hashbrown::HashTable;
+
 /// Tries to convert an [`IntoIterator`] into a container that may fail to be constructed.
 ///
 /// This trait is similar to [`FromIterator`], but can uphold a container's invariant and
@@ -12,6 +38,14 @@ use std::collections::HashMap;
 /// number of elements in the iterator in order to optimize their implementations. A size hint
 /// that provides incorrect bounds may cause panics, produce incorrect results, or produce a
 /// result that violates container constraints, but must not result in undefined behavior.
+/// Such iterators can be detected with [`CheckedSizeHint`], or, for fixed capacity containers,
+/// by collecting with [`TryCollectEx::try_collect_strict`].
+///
+/// Provided implementations pre-allocate from the lower bound of the size hint, limited by
+/// [`prealloc::max`](crate::prealloc::max), using the collection's infallible `with_capacity` or
+/// `reserve`, so an oversized hint panics or aborts if it can not be allocated, rather than
+/// returning an error. To reserve fallibly, call [`TryCollectEx::try_reserve_into`] first,
+/// which returns an error holding the untouched iterator if the allocation fails.
 ///
 /// Implementations are encouraged to return all the data consumed by the iterator, as well
 /// as the partially consumed iterator on an error, but are not required to do so.
@@ -48,6 +82,46 @@ pub trait TryFromIterator<I: IntoIterator>: Sized {
     fn try_from_iter(into_iter: I) -> Result<Self, Self::Error>;
 }
 
+/// Tries to convert an [`IntoIterator`] into a container whose length must fall
+/// within a caller-specified capacity.
+///
+/// This trait is similar to [`TryFromIterator`], but is intended for containers,
+/// such as [`Box<[T]>`](alloc::boxed::Box) or [`Rc<[T]>`](alloc::rc::Rc), which
+/// have no fixed capacity of their own. Instead the allowed length is provided by
+/// the caller as a [`SizeHint`], which may be exact or bounded. Containers
+/// implementing this trait can be collected into via
+/// [`TryCollectEx::try_collect_ex_with_cap`].
+///
+/// Like with [`TryFromIterator`], implementations may rely on [`Iterator::size_hint`]
+/// providing reliable bounds for the number of elements in the iterator.
+#[cfg(feature = "alloc")]
+pub trait TryFromIteratorWithCap<I: IntoIterator>: Sized {
+    /// The error that may occur when converting the iterator into the container.
+    type Error;
+
+    /// Tries to convert an iterator into a container, failing if the iterator
+    /// does not produce a number of items within `cap`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryFromIteratorWithCap::Error`] error if the container fails to be constructed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryFromIteratorWithCap;
+    /// use collect_failable::errors::types::SizeHint;
+    /// use std::rc::Rc;
+    ///
+    /// let slice = Rc::<[i32]>::try_from_iter_with_cap(1..=3, SizeHint::at_most(3)).expect("should fit");
+    /// assert_eq!(*slice, [1, 2, 3]);
+    ///
+    /// let err = Rc::<[i32]>::try_from_iter_with_cap(1..=3, SizeHint::exact(2)).expect_err("should not fit");
+    /// assert_eq!(err.into_iter().collect::<Vec<_>>(), [1, 2, 3], "no items should be lost");
+    /// ```
+    fn try_from_iter_with_cap(into_iter: I, cap: SizeHint) -> Result<Self, Self::Error>;
+}
+
 /// Extends [Iterator] with a failable collect method.
 ///
 /// This trait allows an iterator to return any collection that can be created via
@@ -85,6 +159,335 @@ pub trait TryCollectEx: Iterator {
     where
         C: TryFromIterator<Self>,
         Self: Sized;
+
+    /// Tries to collect the iterator into a container, returning an error if the
+    /// iterator does not produce a number of items within `cap`.
+    ///
+    /// This is the [`TryFromIteratorWithCap`] equivalent of [`TryCollectEx::try_collect_ex`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryFromIteratorWithCap::Error`] if the container fails to be constructed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryCollectEx;
+    /// use collect_failable::errors::types::SizeHint;
+    /// use std::sync::Arc;
+    ///
+    /// let slice: Arc<[_]> = (1..=3).try_collect_ex_with_cap(SizeHint::bounded(2, 4)).expect("should fit");
+    /// assert_eq!(*slice, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_collect_ex_with_cap<C>(self, cap: SizeHint) -> Result<C, C::Error>
+    where
+        C: TryFromIteratorWithCap<Self>,
+        Self: Sized;
+
+    /// Collects the iterator into a container, validating each item with `validate`
+    /// before it is collected.
+    ///
+    /// Collection short-circuits on the first item that fails validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] with an [`Invalid`] error if an item fails validation.
+    /// The rejected item, the collected items, and the remaining iterator are preserved
+    /// in the error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryCollectEx;
+    ///
+    /// let positive = |n: &i32| if *n > 0 { Ok(()) } else { Err("not positive") };
+    ///
+    /// let nums: Vec<_> = [1, 2, 3].into_iter().try_collect_validated(positive).expect("should be valid");
+    /// assert_eq!(nums, vec![1, 2, 3]);
+    ///
+    /// let err = [1, -2, 3].into_iter().try_collect_validated::<Vec<_>, _, _>(positive).expect_err("should be invalid");
+    /// assert_eq!(err.error.item, -2);
+    /// assert_eq!(err.error.error, "not positive");
+    /// assert_eq!(err.into_iter().collect::<Vec<_>>(), vec![-2, 1, 3], "no items should be lost");
+    /// ```
+    fn try_collect_validated<C, F, E>(self, validate: F) -> Result<C, InvalidCollectError<Self, C, E>>
+    where
+        C: FromIterator<Self::Item>,
+        F: FnMut(&Self::Item) -> Result<(), E>,
+        Self: Sized;
+
+    /// Collects clones of the iterator's borrowed items into a new container, cloning only the
+    /// items that are accepted.
+    ///
+    /// This is the [`TryExtendCloned`] equivalent of [`TryCollectEx::try_collect_ex`], extending
+    /// [`Default::default`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] if an item is rejected. The rejected item is returned still
+    /// borrowed, along with the partially collected container and the remaining items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryCollectEx;
+    /// use std::collections::HashSet;
+    ///
+    /// let names = [String::from("a"), String::from("b"), String::from("a")];
+    ///
+    /// let err = names.iter().try_collect_cloned::<HashSet<String>, _>().expect_err("should collide");
+    /// assert_eq!(err.error.item, &names[2], "rejected item should be borrowed");
+    /// assert_eq!(err.position, Some(2));
+    /// ```
+    fn try_collect_cloned<C, E>(self) -> Result<C, CollectError<Self, C, E>>
+    where
+        C: Default + TryExtendCloned<Self, Error = ExtendError<Self, E>>,
+        Self: Sized;
+
+    /// Tries to extend an existing container with the iterator, providing a **basic error guarantee**.
+    ///
+    /// This is the iterator-side equivalent of [`TryExtend::try_extend`], similar to the nightly
+    /// `Iterator::collect_into`. On success, returns `collection` to allow further chaining.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryExtend::Error`] if extending the container fails. `collection` may be
+    /// partially modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryCollectEx;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map = HashMap::from([(1, 2)]);
+    /// let len = [(2, 3), (3, 4)].into_iter().try_collect_into(&mut map).expect("should not collide").len();
+    /// assert_eq!(len, 3);
+    ///
+    /// let err = [(4, 5), (1, 3)].into_iter().try_collect_into(&mut map).expect_err("should collide");
+    /// assert_eq!(err.error.item, (1, 3));
+    /// ```
+    fn try_collect_into<C>(self, collection: &mut C) -> Result<&mut C, C::Error>
+    where
+        C: TryExtend<Self>,
+        Self: Sized;
+
+    /// Collects the iterator into a container, skipping every item that is rejected, and
+    /// returning the position and error of every skipped item along with the result.
+    ///
+    /// Each time collection fails, the rejected item is skipped, and collection resumes as with
+    /// [`CollectError::resume`], reusing the partially collected container.
+    ///
+    /// If an error does not hold a rejected item, such as a
+    /// [`Bounds`](crate::errors::capacity::CapacityErrorKind::Bounds) capacity error, or its
+    /// position is unknown, no item can be skipped. Collection then stops, and the result is
+    /// that [`CollectError`], holding the container and the remaining items.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryCollectEx;
+    /// use std::collections::HashMap;
+    ///
+    /// let (skipped, result) = [(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (2, 'e')]
+    ///     .into_iter()
+    ///     .try_collect_skipping::<HashMap<_, _>, _>();
+    ///
+    /// assert_eq!(result.expect("should skip every collision"), HashMap::from([(1, 'a'), (2, 'b'), (3, 'd')]));
+    /// assert_eq!(skipped.into_iter().map(|(position, err)| (position, err.item)).collect::<Vec<_>>(), [(2, (1, 'c')), (4, (2, 'e'))]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_collect_skipping<C, E>(self) -> SkippingResult<Self, C, E>
+    where
+        C: TryFromIterator<Self, Error = CollectError<Self, C, E>> + TryExtend<Self, Error = ExtendError<Self, E>>,
+        E: ErrorItemProvider,
+        Self: Sized;
+
+    /// Collects the iterator into a container one item at a time, never failing, and
+    /// returning the container along with the position and error of every rejected item.
+    ///
+    /// Unlike [`try_collect_skipping`](TryCollectEx::try_collect_skipping), this is built on
+    /// [`TryExtendOne`], so works with any container that implements it, and rejects items
+    /// that would overflow a fixed capacity collection rather than stopping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryCollectEx;
+    /// use std::collections::HashMap;
+    ///
+    /// let (map, rejects) = [(1, 'a'), (2, 'b'), (1, 'c')].into_iter().collect_partitioned::<HashMap<_, _>>();
+    ///
+    /// assert_eq!(map, HashMap::from([(1, 'a'), (2, 'b')]));
+    /// assert_eq!(rejects.into_iter().map(|(position, err)| (position, err.item)).collect::<Vec<_>>(), [(2, (1, 'c'))]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn collect_partitioned<C>(self) -> (C, Vec<(usize, C::Error)>)
+    where
+        C: Default + TryExtendOne<Item = Self::Item>,
+        Self: Sized;
+
+    /// Tries to extend an existing container with the iterator, providing a **strong error guarantee**.
+    ///
+    /// This is the iterator-side equivalent of [`TryExtendSafe::try_extend_safe`]. On success,
+    /// returns `collection` to allow further chaining.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryExtendSafe::Error`] if extending the container fails. `collection` is
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryCollectEx;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set = BTreeSet::from([1, 2]);
+    /// [3].into_iter().try_collect_into_safe(&mut set).expect("should not collide");
+    ///
+    /// [4, 1].into_iter().try_collect_into_safe(&mut set).expect_err("should collide");
+    /// assert_eq!(set, BTreeSet::from([1, 2, 3]), "set should be unchanged");
+    /// ```
+    fn try_collect_into_safe<C>(self, collection: &mut C) -> Result<&mut C, C::Error>
+    where
+        C: TryExtendSafe<Self>,
+        Self: Sized;
+
+    /// Reserves capacity in `collection` for the iterator's items, reporting an allocation
+    /// failure instead of aborting, and returns the unconsumed iterator.
+    ///
+    /// Space is reserved for [`prealloc::hint`] items. Collecting the returned iterator into
+    /// `collection` afterwards then does not allocate based on its [`size_hint`](Iterator::size_hint) again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExtendError`] containing the unconsumed iterator, and the allocation error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::{TryCollectEx, TryExtend};
+    /// use size_hinter::SizeHinter;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut set = HashSet::new();
+    /// let iter = [1, 2].into_iter().try_reserve_into(&mut set).expect("should allocate");
+    /// set.try_extend(iter).expect("should not collide");
+    ///
+    /// // an iterator claiming far more items than can be allocated
+    /// let iter = (3..=4).hide_size().hint_min(usize::MAX);
+    /// let err = iter.try_reserve_into(&mut set).expect_err("should fail to allocate");
+    /// assert_eq!(err.into_data().remain.count(), 2, "no items should be lost");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_reserve_into<C>(self, collection: &mut C) -> Result<Self, ExtendError<Self, C::Error>>
+    where
+        C: TryReserve,
+        Self: Sized;
+
+    /// Tries to collect the iterator into a [`HashTable`], using `hasher` and `eq` to detect
+    /// colliding items.
+    ///
+    /// [`HashTable`] does not store a hasher, so items are hashed with `hasher`, and two items
+    /// with the same hash collide if `eq` returns `true`. To keep extending the table with the
+    /// same functions, see [`HashTableWith`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TableCollectError`] containing the unconsumed iterator, the items collected so
+    /// far, and a [`Collision`](crate::errors::Collision) holding the rejected item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryCollectEx;
+    /// use std::hash::BuildHasher;
+    ///
+    /// let state = hashbrown::DefaultHashBuilder::default();
+    /// let hasher = |(id, _): &(u32, &str)| state.hash_one(id);
+    /// let eq = |(a, _): &(u32, &str), (b, _): &(u32, &str)| a == b;
+    ///
+    /// let table = [(1, "a"), (2, "b")].into_iter().try_collect_table(hasher, eq).expect("should not collide");
+    /// assert_eq!(table.len(), 2);
+    ///
+    /// let err = [(1, "a"), (1, "b")].into_iter().try_collect_table(hasher, eq).expect_err("should collide");
+    /// assert_eq!(err.error.item, (1, "b"));
+    /// ```
+    #[cfg(feature = "hashbrown")]
+    fn try_collect_table<H, E>(self, hasher: H, eq: E) -> Result<HashTable<Self::Item>, TableCollectError<Self>>
+    where
+        H: Fn(&Self::Item) -> u64,
+        E: Fn(&Self::Item, &Self::Item) -> bool,
+        Self: Sized;
+
+    /// Tries to collect the iterator into a fixed capacity container, reporting an iterator that
+    /// violates its [`size_hint`](Iterator::size_hint) rather than panicking.
+    ///
+    /// The iterator is wrapped in a [`CheckedSizeHint`] before collecting. An invalid hint fails
+    /// immediately, without consuming any items. If collection then fails because of a violated
+    /// hint, the error's kind is [`SizeHint`](CapacityErrorKind::SizeHint), unless the error holds
+    /// an overflowing item, which is kept so that no items are lost.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] if the container fails to be constructed, or the iterator's
+    /// hint is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use collect_failable::TryCollectEx;
+    /// use collect_failable::errors::capacity::CapacityErrorKind;
+    /// use collect_failable::errors::size_hint::SizeHintViolationKind;
+    /// use size_hinter::InvalidIterator;
+    ///
+    /// let err = InvalidIterator::<i32>::new().try_collect_strict::<[i32; 3], _>().expect_err("should be invalid");
+    ///
+    /// assert!(matches!(err.error.kind, CapacityErrorKind::SizeHint { violation } if violation.kind == SizeHintViolationKind::Invalid));
+    /// ```
+    fn try_collect_strict<C, P>(self) -> Result<C, CollectError<Self, P, CapacityError<Self::Item>>>
+    where
+        C: FixedCap
+            + TryFromIterator<CheckedSizeHint<Self>, Error = CollectError<CheckedSizeHint<Self>, P, CapacityError<Self::Item>>>,
+        P: Default,
+        Self: Sized;
+
+    /// Tries to extend an existing fixed capacity container with the iterator, reporting an
+    /// iterator that violates its [`size_hint`](Iterator::size_hint) rather than panicking.
+    ///
+    /// This is the strict equivalent of [`try_collect_into`](TryCollectEx::try_collect_into),
+    /// checking the iterator as with [`try_collect_strict`](TryCollectEx::try_collect_strict).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ExtendError`] if extending the container fails, or the iterator's hint is invalid.
+    fn try_collect_into_strict<C>(self, collection: &mut C) -> Result<&mut C, ExtendError<Self, CapacityError<Self::Item>>>
+    where
+        C: RemainingCap + TryExtend<CheckedSizeHint<Self>, Error = ExtendError<CheckedSizeHint<Self>, CapacityError<Self::Item>>>,
+        Self: Sized;
+
+    /// Tries to extend an existing fixed capacity container with the iterator, providing a
+    /// **strong error guarantee**, and reporting an iterator that violates its
+    /// [`size_hint`](Iterator::size_hint) rather than panicking.
+    ///
+    /// This is the strict equivalent of [`try_collect_into_safe`](TryCollectEx::try_collect_into_safe),
+    /// checking the iterator as with [`try_collect_strict`](TryCollectEx::try_collect_strict).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CollectError`] if extending the container fails, or the iterator's hint is
+    /// invalid. `collection` is unchanged.
+    fn try_collect_into_safe_strict<C, P>(
+        self,
+        collection: &mut C,
+    ) -> Result<&mut C, CollectError<Self, P, CapacityError<Self::Item>>>
+    where
+        C: RemainingCap
+            + TryExtendSafe<CheckedSizeHint<Self>, Error = CollectError<CheckedSizeHint<Self>, P, CapacityError<Self::Item>>>,
+        P: Default,
+        Self: Sized;
 }
 
 /// Implementation of [`TryCollectEx`] for all [`Iterator`].
@@ -99,4 +502,173 @@ where
     {
         C::try_from_iter(self)
     }
+
+    #[cfg(feature = "alloc")]
+    fn try_collect_ex_with_cap<C>(self, cap: SizeHint) -> Result<C, C::Error>
+    where
+        C: TryFromIteratorWithCap<Self>,
+    {
+        C::try_from_iter_with_cap(self, cap)
+    }
+
+    fn try_collect_validated<C, F, E>(mut self, mut validate: F) -> Result<C, InvalidCollectError<Self, C, E>>
+    where
+        C: FromIterator<T>,
+        F: FnMut(&T) -> Result<(), E>,
+    {
+        let mut invalid = None;
+        let collected = self
+            .by_ref()
+            .enumerate()
+            .map_while(|(position, item)| match validate(&item) {
+                Ok(()) => Some(item),
+                Err(error) => invalid.insert((position, Invalid::new(item, error))).pipe(|_| None),
+            })
+            .collect();
+
+        match invalid {
+            Some((position, invalid)) => Err(CollectError::new(self, collected, invalid).with_position(position)),
+            None => Ok(collected),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_collect_skipping<C, E>(self) -> SkippingResult<Self, C, E>
+    where
+        C: TryFromIterator<Self, Error = CollectError<Self, C, E>> + TryExtend<Self, Error = ExtendError<Self, E>>,
+        E: ErrorItemProvider,
+    {
+        let mut skipped = Vec::new();
+        let mut result = C::try_from_iter(self);
+
+        while let Err(err) = result {
+            let Some(position) = err.position.filter(|_| err.error.item().is_some()) else {
+                return (skipped, Err(err));
+            };
+
+            let (error, resumed) = err.resume();
+            skipped.push((position, error));
+            result = resumed;
+        }
+
+        (skipped, result)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn collect_partitioned<C>(self) -> (C, Vec<(usize, C::Error)>)
+    where
+        C: Default + TryExtendOne<Item = T>,
+    {
+        let mut collection = C::default();
+        let rejects = self
+            .enumerate()
+            .filter_map(|(position, item)| collection.try_extend_one(item).err().map(|err| (position, err)))
+            .collect();
+
+        (collection, rejects)
+    }
+
+    fn try_collect_cloned<C, E>(self) -> Result<C, CollectError<Self, C, E>>
+    where
+        C: Default + TryExtendCloned<Self, Error = ExtendError<Self, E>>,
+    {
+        let mut collection = C::default();
+
+        match collection.try_extend_cloned(self) {
+            Ok(()) => Ok(collection),
+            Err(err) => {
+                let ExtendErrorData { remain, error, position } = err.into_data();
+                Err(CollectError::from_data(CollectErrorData { remain, collected: collection, error, position }))
+            }
+        }
+    }
+
+    fn try_collect_into<C>(self, collection: &mut C) -> Result<&mut C, C::Error>
+    where
+        C: TryExtend<Self>,
+    {
+        collection.try_extend(self).map(|()| collection)
+    }
+
+    fn try_collect_into_safe<C>(self, collection: &mut C) -> Result<&mut C, C::Error>
+    where
+        C: TryExtendSafe<Self>,
+    {
+        collection.try_extend_safe(self).map(|()| collection)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn try_reserve_into<C>(self, collection: &mut C) -> Result<Self, ExtendError<Self, C::Error>>
+    where
+        C: TryReserve,
+    {
+        match collection.try_reserve(prealloc::hint(&self)) {
+            Ok(()) => Ok(self),
+            Err(error) => ExtendError::new(self, error).into_err(),
+        }
+    }
+
+    #[cfg(feature = "hashbrown")]
+    fn try_collect_table<H, E>(mut self, hasher: H, eq: E) -> Result<HashTable<T>, TableCollectError<Self>>
+    where
+        H: Fn(&T) -> u64,
+        E: Fn(&T, &T) -> bool,
+    {
+        let mut table = HashTableWith::with_capacity(prealloc::hint(&self), hasher, eq);
+
+        match self.try_for_each(|item| table.try_extend_one(item)) {
+            Ok(()) => Ok(table.into_inner()),
+            Err(error) => {
+                let position = table.len();
+                CollectError::new(self, table.into_inner(), error).with_position(position).into_err()
+            }
+        }
+    }
+
+    fn try_collect_strict<C, P>(self) -> Result<C, CollectError<Self, P, CapacityError<T>>>
+    where
+        C: FixedCap + TryFromIterator<CheckedSizeHint<Self>, Error = CollectError<CheckedSizeHint<Self>, P, CapacityError<T>>>,
+        P: Default,
+    {
+        let iter = CheckedSizeHint::new(self);
+
+        match iter.violation() {
+            Some(violation) => {
+                CollectError::new(iter.into_inner(), P::default(), CapacityError::size_hint(C::CAP, violation)).into_err()
+            }
+            None => C::try_from_iter(iter).map_err(CheckedSizeHint::strict_collect_error),
+        }
+    }
+
+    fn try_collect_into_strict<C>(self, collection: &mut C) -> Result<&mut C, ExtendError<Self, CapacityError<T>>>
+    where
+        C: RemainingCap + TryExtend<CheckedSizeHint<Self>, Error = ExtendError<CheckedSizeHint<Self>, CapacityError<T>>>,
+    {
+        let iter = CheckedSizeHint::new(self);
+
+        match iter.violation() {
+            Some(violation) => {
+                ExtendError::new(iter.into_inner(), CapacityError::size_hint(collection.remaining_cap(), violation)).into_err()
+            }
+            None => collection.try_extend(iter).map(|()| collection).map_err(CheckedSizeHint::strict_extend_error),
+        }
+    }
+
+    fn try_collect_into_safe_strict<C, P>(self, collection: &mut C) -> Result<&mut C, CollectError<Self, P, CapacityError<T>>>
+    where
+        C: RemainingCap + TryExtendSafe<CheckedSizeHint<Self>, Error = CollectError<CheckedSizeHint<Self>, P, CapacityError<T>>>,
+        P: Default,
+    {
+        let iter = CheckedSizeHint::new(self);
+
+        match iter.violation() {
+            Some(violation) => CollectError::new(
+                iter.into_inner(),
+                P::default(),
+                CapacityError::size_hint(collection.remaining_cap(), violation),
+            )
+            .into_err(),
+            None => collection.try_extend_safe(iter).map(|()| collection).map_err(CheckedSizeHint::strict_collect_error),
+        }
+    }
 }
@@ -0,0 +1,90 @@
+#[cfg(doc)]
+use crate::errors::{Collision, SortedError};
+#[cfg(doc)]
+use crate::{TryExtendSafe, TryFromIterator};
+#[cfg(doc)]
+use std::collections::BTreeMap;
+
+/// Tries to convert an [`IntoIterator`] of sorted items into a container that may
+/// fail to be constructed.
+///
+/// This trait is similar to [`TryFromIterator`], but requires the input be strictly
+/// increasing, allowing the container to be built in bulk. Duplicates are detected by
+/// comparing adjacent items, and an item that is out of order is rejected instead of
+/// being sorted.
+///
+/// Implementations are encouraged to return all the data consumed by the iterator, as well
+/// as the partially consumed iterator on an error, but are not required to do so.
+pub trait TryFromSortedIterator<I: IntoIterator>: Sized {
+    /// The error that may occur when converting the iterator into the container.
+    type Error;
+
+    /// Tries to convert an iterator of sorted items into a container.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryFromSortedIterator::Error`] error if the container fails to be
+    /// constructed, including if the input is not sorted.
+    ///
+    /// # Example
+    ///
+    /// The provided [`BTreeMap`] implementation errors if a key would collide, or is out of order.
+    ///
+    /// ```rust
+    /// use collect_failable::TryFromSortedIterator;
+    /// use collect_failable::errors::sorted::SortedErrorKind;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let map = BTreeMap::try_from_sorted_iter([(1, 2), (2, 3)]).expect("should succeed");
+    /// assert_eq!(map, BTreeMap::from([(1, 2), (2, 3)]));
+    ///
+    /// let err = BTreeMap::try_from_sorted_iter([(2, 3), (1, 2)]).expect_err("should fail, out of order");
+    /// assert_eq!(err.error.item, (1, 2), "out of order item should be (1, 2)");
+    /// assert_eq!(err.error.kind, SortedErrorKind::NotSorted);
+    /// ```
+    fn try_from_sorted_iter(into_iter: I) -> Result<Self, Self::Error>;
+}
+
+/// Trait for extending a collection from sorted items with a **strong error guarantee**.
+///
+/// This trait is similar to [`TryExtendSafe`], but requires the input be strictly
+/// increasing, allowing the items to be merged into the collection in bulk.
+/// On an error, the collection is unchanged.
+///
+/// This is a separate trait because its contract differs from [`TryExtendSafe`], which
+/// accepts items in any order and fails only on a [`Collision`]. Here, out of order input is
+/// itself an error, reported as a [`SortedError`] with a `NotSorted` kind. A collection can
+/// implement [`TryExtendSafe`] only once for a given iterator type, so the two can not be
+/// distinguished by implementation. The [`BTreeMap`] [`TryExtendSafe`] implementation also
+/// merges via [`BTreeMap::append`], but must first stage the items in a tree, doing a lookup
+/// per item, since its input may be unsorted.
+pub trait TryExtendSorted<I: IntoIterator> {
+    /// The type of error that can occur during extension.
+    type Error;
+
+    /// Tries to extend the collection with sorted items, providing a **strong error guarantee**.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryExtendSorted::Error`] if a failure occurs while extending the collection,
+    /// including if the input is not sorted.
+    ///
+    /// # Examples
+    ///
+    /// The provided [`BTreeMap`] implementation errors if a key would collide, or is out of order.
+    ///
+    /// ```rust
+    /// # use collect_failable::TryExtendSorted;
+    /// # use collect_failable::errors::sorted::SortedErrorKind;
+    /// # use std::collections::BTreeMap;
+    /// let mut map = BTreeMap::from([(1, 2), (5, 6)]);
+    /// map.try_extend_sorted([(2, 3), (3, 4)]).expect("should succeed");
+    ///
+    /// let err = map.try_extend_sorted([(4, 5), (5, 7)]).expect_err("should collide");
+    /// assert_eq!(err.error.item, (5, 7), "item should be the colliding item");
+    /// assert_eq!(err.error.kind, SortedErrorKind::Collision);
+    ///
+    /// assert_eq!(map, BTreeMap::from([(1, 2), (2, 3), (3, 4), (5, 6)]), "map should be unchanged");
+    /// ```
+    fn try_extend_sorted(&mut self, iter: I) -> Result<(), Self::Error>;
+}
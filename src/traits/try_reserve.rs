@@ -0,0 +1,19 @@
+#[cfg(doc)]
+use crate::TryCollectEx;
+
+/// Trait for collections that can reserve capacity, reporting an allocation failure instead of
+/// aborting.
+///
+/// This is used by [`TryCollectEx::try_reserve_into`] to pre-allocate space for an iterator
+/// over untrusted input before collecting it.
+pub trait TryReserve {
+    /// The type of error returned if the allocation fails.
+    type Error;
+
+    /// Tries to reserve capacity for at least `additional` more items.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryReserve::Error`] if the capacity overflows, or the allocator reports a failure.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), Self::Error>;
+}
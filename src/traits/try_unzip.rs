@@ -102,13 +102,14 @@ impl<I: Iterator> TryUnzip for I {
     {
         let mut from = (FromA::default(), FromB::default());
 
-        for (a, b) in self.by_ref() {
+        for (position, (a, b)) in self.by_ref().enumerate() {
             if let Err(error) = from.0.try_extend_one(a) {
-                return UnzipError::new(error, from.0, from.1, Some(b), self).pipe(Either::Left).into_err();
+                return UnzipError::new(error, from.0, from.1, Some(b), self).with_position(position).pipe(Either::Left).into_err();
             }
 
             if let Err(error) = from.1.try_extend_one(b) {
                 return UnzipError::new(error, from.1, from.0, None::<FromA::Item>, self)
+                    .with_position(position)
                     .pipe::<EitherUnzipError<FromA, FromB, Self>>(Either::Right)
                     .into_err();
             }
@@ -0,0 +1,143 @@
+use core::ops::{Deref, DerefMut};
+
+/// A target of a [`Transaction`], which can be staged and later committed.
+///
+/// Implemented for mutable references to any [`Clone`] container, and for tuples of
+/// targets, so several containers can be updated together.
+pub trait Transactional {
+    /// The staged copy of the target, which changes are made to.
+    type Staged;
+
+    /// Creates a staged copy of the target.
+    fn stage(&self) -> Self::Staged;
+
+    /// Replaces the target with `staged`.
+    fn commit(self, staged: Self::Staged);
+}
+
+impl<C: Clone> Transactional for &mut C {
+    type Staged = C;
+
+    fn stage(&self) -> Self::Staged {
+        (**self).clone()
+    }
+
+    fn commit(self, staged: Self::Staged) {
+        *self = staged;
+    }
+}
+
+/// Implements [`Transactional`] for a tuple of [`Transactional`] targets.
+macro_rules! impl_transactional_for_tuple {
+    ($($name:ident: $index:tt),+) => {
+        impl<$($name: Transactional),+> Transactional for ($($name,)+) {
+            type Staged = ($($name::Staged,)+);
+
+            fn stage(&self) -> Self::Staged {
+                ($(self.$index.stage(),)+)
+            }
+
+            fn commit(self, staged: Self::Staged) {
+                $(self.$index.commit(staged.$index);)+
+            }
+        }
+    };
+}
+
+impl_transactional_for_tuple!(A: 0, B: 1);
+impl_transactional_for_tuple!(A: 0, B: 1, C: 2);
+
+/// A guard that stages changes to one or more containers, and applies them atomically.
+///
+/// Changes are made to a staged copy of the targets, accessible via [`Deref`] and
+/// [`DerefMut`], so any number of [`TryExtend`](crate::TryExtend) and
+/// [`TryExtendOne`](crate::TryExtendOne) calls can be made. The targets are only modified
+/// by [`commit`](Transaction::commit). Dropping the transaction, including by returning
+/// early on an error, discards every staged change.
+///
+/// Staging clones each target, so this is best suited to keeping several containers
+/// consistent, rather than to large containers.
+///
+/// # Examples
+///
+/// ```rust
+/// # use collect_failable::{Transaction, TryExtendOne};
+/// # use std::collections::{BTreeSet, HashMap};
+/// fn insert(
+///     users: &mut HashMap<u32, &'static str>,
+///     names: &mut BTreeSet<&'static str>,
+///     new: &[(u32, &'static str)],
+/// ) -> Result<(), Box<dyn std::error::Error>> {
+///     let mut tx = Transaction::begin((users, names));
+///     for &(id, name) in new {
+///         tx.0.try_extend_one((id, name))?;
+///         tx.1.try_extend_one(name)?;
+///     }
+///     tx.commit();
+///     Ok(())
+/// }
+///
+/// let mut users = HashMap::from([(1, "alice")]);
+/// let mut names = BTreeSet::from(["alice"]);
+///
+/// insert(&mut users, &mut names, &[(2, "bob")]).expect("should insert");
+/// insert(&mut users, &mut names, &[(3, "carol"), (4, "alice")]).expect_err("name should collide");
+///
+/// assert_eq!(users, HashMap::from([(1, "alice"), (2, "bob")]), "users should be rolled back");
+/// assert_eq!(names, BTreeSet::from(["alice", "bob"]), "names should be rolled back");
+/// ```
+#[derive(Debug)]
+#[must_use = "a transaction is rolled back unless committed"]
+pub struct Transaction<T: Transactional> {
+    target: T,
+    staged: T::Staged,
+}
+
+impl<T: Transactional> Transaction<T> {
+    /// Begins a transaction over `target`, staging a copy of it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use collect_failable::{Transaction, TryExtend};
+    /// # use std::collections::HashSet;
+    /// let mut set = HashSet::from([1]);
+    ///
+    /// let mut tx = Transaction::begin(&mut set);
+    /// tx.try_extend([2, 3]).expect("should not collide");
+    /// tx.try_extend([4, 1]).expect_err("should collide");
+    /// tx.rollback();
+    ///
+    /// assert_eq!(set, HashSet::from([1]), "set should be unchanged");
+    /// ```
+    pub fn begin(target: T) -> Self {
+        let staged = target.stage();
+        Self { target, staged }
+    }
+
+    /// Applies every staged change to the targets.
+    pub fn commit(self) {
+        self.target.commit(self.staged);
+    }
+
+    /// Discards every staged change, leaving the targets unchanged.
+    ///
+    /// This is equivalent to dropping the transaction.
+    pub fn rollback(self) {
+        drop(self);
+    }
+}
+
+impl<T: Transactional> Deref for Transaction<T> {
+    type Target = T::Staged;
+
+    fn deref(&self) -> &Self::Target {
+        &self.staged
+    }
+}
+
+impl<T: Transactional> DerefMut for Transaction<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.staged
+    }
+}
@@ -28,10 +28,10 @@ mod format {
     use crate::error_tests::test_format;
 
     const EXPECTED_DEBUG: &str =
-        r#"CollectError { remain: core::ops::range::Range<i32>, collected: [i32; 2], error: TestError("test") }"#;
+        r#"CollectError { remain: core::ops::range::Range<i32>, collected: [i32; 2], error: TestError("test"), position: None }"#;
     const EXPECTED_DISPLAY: &str = "Collection Error: Test error: test";
     const EXPECTED_DEBUG_DATA: &str =
-        r#"CollectErrorData { remain: core::ops::range::Range<i32>, collected: [i32; 2], error: TestError("test") }"#;
+        r#"CollectErrorData { remain: core::ops::range::Range<i32>, collected: [i32; 2], error: TestError("test"), position: None }"#;
 
     test_format!(debug, CollectError::new(REMAIN_ITER, COLLECTED, TEST_ERROR), "{:?}", EXPECTED_DEBUG);
     test_format!(display, CollectError::new(REMAIN_ITER, COLLECTED, TEST_ERROR), "{}", EXPECTED_DISPLAY);
@@ -204,3 +204,97 @@ mod ensure_fits_into {
 
     panics!(panic, CollectError::ensure_fits_into(INVALID_ITER, &ArrayVec::new()), "Invalid size hint");
 }
+
+mod into_owned {
+    use super::*;
+
+    fn assert_send_sync_static<T: Send + Sync + 'static>(_: &T) {}
+
+    #[test]
+    fn valid() {
+        let data = CollectError::new(REMAIN_ITER, COLLECTED, TEST_ERROR).into_owned().into_data();
+        assert_eq!(data.remain.as_slice(), [3, 4]);
+        assert_eq!(data.collected, vec![1, 2]);
+        assert_eq!(data.error, TEST_ERROR);
+    }
+
+    #[test]
+    fn erases_borrowed_iter() {
+        let remain = vec![3, 4];
+        let owned = CollectError::new(remain.iter().copied(), COLLECTED, TEST_ERROR).into_owned();
+        drop(remain);
+        assert_send_sync_static(&owned);
+    }
+
+    test_source!(source, CollectError::new(REMAIN_ITER, COLLECTED, TEST_ERROR).into_owned(), TestError<i32>);
+    test_into_iter!(into_iter, CollectError::collision(REMAIN_ITER, COLLECTED, 0).into_owned(), vec![0, 1, 2, 3, 4]);
+}
+
+mod map {
+    use collect_failable::errors::ExtendError;
+
+    use super::*;
+
+    test_ctor!(
+        map_error,
+        CollectError::new(REMAIN_ITER, COLLECTED, TEST_ERROR).map_error(|error| error.identity),
+        remain => REMAIN_ITER,
+        collected => COLLECTED,
+        error => "test"
+    );
+    test_ctor!(
+        map_collected,
+        CollectError::new(REMAIN_ITER, COLLECTED, TEST_ERROR).map_collected(Vec::from),
+        remain => REMAIN_ITER,
+        collected => vec![1, 2],
+        error => TEST_ERROR
+    );
+    test_ctor!(
+        map_remain,
+        CollectError::new(REMAIN_ITER, COLLECTED, TEST_ERROR).map_remain(Vec::from_iter),
+        remain => vec![3, 4],
+        collected => COLLECTED,
+        error => TEST_ERROR
+    );
+    test_ctor!(
+        map_items,
+        CollectError::collision(REMAIN_ITER, COLLECTED, 0).map_items::<_, Vec<_>, _>(|n| n * 10).map_remain(Vec::from_iter),
+        remain => vec![30, 40],
+        collected => vec![10, 20],
+        error => Collision::new(0)
+    );
+    test_into_iter!(
+        map_items_into_iter,
+        CollectError::collision(REMAIN_ITER, COLLECTED, 0).map_items::<_, Vec<_>, _>(|n| n + 1),
+        vec![1, 2, 3, 4, 5]
+    );
+    test_into_iter!(
+        into_extend_error,
+        ExtendError::from(CollectError::collision(REMAIN_ITER, COLLECTED, 0)),
+        vec![0, 1, 2, 3, 4]
+    );
+}
+
+mod resume {
+    use std::collections::BTreeSet;
+
+    use collect_failable::TryFromIterator;
+
+    #[test]
+    fn completes() {
+        let err = BTreeSet::try_from_iter([1, 2, 1, 3]).expect_err("should collide");
+        let (skipped, result) = err.resume();
+        assert_eq!(skipped.item, 1, "skipped error should be returned");
+        assert_eq!(result.expect("should not collide"), BTreeSet::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn fails_again() {
+        let err = BTreeSet::try_from_iter([1, 1, 2, 2, 3]).expect_err("should collide");
+        let data = err.resume().1.expect_err("should collide again").into_data();
+        assert_eq!(data.remain.collect::<Vec<_>>(), [3]);
+        assert_eq!(data.collected, BTreeSet::from([1, 2]));
+        assert_eq!(data.error.item, 2);
+        assert_eq!(data.position, Some(3), "position should be relative to the original input");
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use collect_failable::TryFromIterator;
+use collect_failable::errors::capacity::CapacityError;
+use collect_failable::errors::size_hint::SizeHintViolation;
+use collect_failable::errors::sorted::SortedError;
+use collect_failable::errors::types::SizeHint;
+use collect_failable::errors::{CollectError, Collision, ExtendError, Invalid};
+use miette::Diagnostic;
+
+use crate::error_tests::TestError;
+
+macro_rules! test_diagnostic {
+    ($name:ident, $error:expr, $code:expr, $help:expr) => {
+        #[test]
+        fn $name() {
+            let error = $error;
+            assert_eq!(error.code().map(|code| code.to_string()).as_deref(), Some($code));
+            assert_eq!(error.help().map(|help| help.to_string()).as_deref(), Some($help));
+        }
+    };
+}
+
+test_diagnostic!(collision, Collision::new(1), "collect_failable::collision", "remove the duplicate key or use a merge policy");
+
+test_diagnostic!(
+    capacity_bounds,
+    CapacityError::<i32>::bounds(SizeHint::bounded(1, 3), SizeHint::unbounded(5)),
+    "collect_failable::capacity::bounds",
+    "the collection requires between 1 and 3 items, but the input produces at least 5 items"
+);
+test_diagnostic!(
+    capacity_underflow,
+    CapacityError::<i32>::underflow(SizeHint::exact(3), 1),
+    "collect_failable::capacity::underflow",
+    "the collection requires exactly 3 items, but the input produced 1"
+);
+test_diagnostic!(
+    capacity_overflow,
+    CapacityError::overflow(SizeHint::at_most(2), 3),
+    "collect_failable::capacity::overflow",
+    "the collection requires at most 2 items, remove the excess items"
+);
+test_diagnostic!(
+    capacity_size_hint,
+    CapacityError::<i32>::size_hint(SizeHint::exact(3), SizeHintViolation::underflow((3, None), 1)),
+    "collect_failable::capacity::size_hint",
+    "the input's `size_hint` does not match the items it produces, fix its implementation"
+);
+
+test_diagnostic!(sorted_collision, SortedError::collision(1), "collect_failable::sorted::collision", "remove the duplicate item");
+test_diagnostic!(
+    sorted_not_sorted,
+    SortedError::not_sorted(1),
+    "collect_failable::sorted::not_sorted",
+    "sort the input, or collect without requiring sorted input"
+);
+
+test_diagnostic!(
+    invalid,
+    Invalid::new(1, TestError::<()>::new("invalid")),
+    "collect_failable::invalid",
+    "remove or correct the invalid item"
+);
+
+test_diagnostic!(
+    collect_error_forwards,
+    HashSet::<i32>::try_from_iter([1, 2, 1]).expect_err("should collide"),
+    "collect_failable::collision",
+    "remove the duplicate key or use a merge policy (rejected item at position 2)"
+);
+test_diagnostic!(
+    extend_error_forwards,
+    ExtendError::overflow(0..0, 1),
+    "collect_failable::capacity::overflow",
+    "the collection requires exactly 0 items, remove the excess items"
+);
+#[test]
+fn into_report() {
+    let report = miette::Report::new(CollectError::collision(0..0, Vec::<i32>::new(), 1).into_owned());
+    assert_eq!(report.code().map(|code| code.to_string()).as_deref(), Some("collect_failable::collision"));
+}
+
+#[test]
+fn position_not_labeled() {
+    assert!(HashSet::<i32>::try_from_iter([1, 2, 1]).expect_err("should collide").labels().is_none());
+}
@@ -17,9 +17,9 @@ mod format {
     use super::*;
     use crate::error_tests::test_format;
 
-    const EXPECTED_DEBUG: &str = r#"ExtendError { remain: core::ops::range::Range<i32>, error: TestError("test") }"#;
+    const EXPECTED_DEBUG: &str = r#"ExtendError { remain: core::ops::range::Range<i32>, error: TestError("test"), position: None }"#;
     const EXPECTED_DISPLAY: &str = "Extension Error: Test error: test";
-    const EXPECTED_DEBUG_DATA: &str = r#"ExtendErrorData { remain: core::ops::range::Range<i32>, error: TestError("test") }"#;
+    const EXPECTED_DEBUG_DATA: &str = r#"ExtendErrorData { remain: core::ops::range::Range<i32>, error: TestError("test"), position: None }"#;
 
     test_format!(debug, ExtendError::new(ITER, TEST_ERROR), "{:?}", EXPECTED_DEBUG);
     test_format!(display, ExtendError::new(ITER, TEST_ERROR), "{}", EXPECTED_DISPLAY);
@@ -125,3 +125,64 @@ mod ensure_fits_into {
         "Capacity must have an upper bound to overflow"
     );
 }
+
+mod into_owned {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let data = ExtendError::new(ITER, TEST_ERROR).into_owned().into_data();
+        assert_eq!(data.remain.as_slice(), [1, 2]);
+        assert_eq!(data.error, TEST_ERROR);
+    }
+
+    test_source!(source, ExtendError::new(ITER, TEST_ERROR).into_owned(), TestError<i32>);
+    test_into_iter!(into_iter, ExtendError::overflow(ITER, OVERFLOW_VALUE).into_owned(), vec![OVERFLOW_VALUE, 1, 2]);
+}
+
+mod map {
+    use super::*;
+
+    #[test]
+    fn map_error() {
+        let data = ExtendError::new(ITER, TEST_ERROR).map_error(|error| error.identity).into_data();
+        assert_eq!(data.remain, ITER);
+        assert_eq!(data.error, "test");
+    }
+
+    #[test]
+    fn map_remain() {
+        let data = ExtendError::new(ITER, TEST_ERROR).map_remain(Iterator::count).into_data();
+        assert_eq!(data.remain, 2);
+        assert_eq!(data.error, TEST_ERROR);
+    }
+
+    test_into_iter!(map_items, ExtendError::overflow(ITER, OVERFLOW_VALUE).map_items(|n| n * 10), vec![990, 10, 20]);
+}
+
+mod resume_into {
+    use std::collections::HashSet;
+
+    use collect_failable::TryExtend;
+
+    #[test]
+    fn completes() {
+        let mut set = HashSet::from([1]);
+        let err = set.try_extend([2, 1, 3]).expect_err("should collide");
+        let (skipped, result) = err.resume_into(&mut set);
+        assert_eq!(skipped.item, 1, "skipped error should be returned");
+        result.expect("should not collide");
+        assert_eq!(set, HashSet::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn fails_again() {
+        let mut set = HashSet::from([1]);
+        let err = set.try_extend([1, 2, 2, 3]).expect_err("should collide");
+        let data = err.resume_into(&mut set).1.expect_err("should collide again").into_data();
+        assert_eq!(data.remain.collect::<Vec<_>>(), [3]);
+        assert_eq!(data.error.item, 2);
+        assert_eq!(data.position, Some(2), "position should be relative to the original input");
+        assert_eq!(set, HashSet::from([1, 2]));
+    }
+}
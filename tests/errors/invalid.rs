@@ -0,0 +1,23 @@
+use collect_failable::errors::CollectError;
+use collect_failable::errors::invalid::Invalid;
+
+use collect_failable::errors::Collision;
+
+crate::error_tests::test_ctor!(
+    new,
+    Invalid::new(42, "error"),
+    item => 42,
+    error => "error"
+);
+
+crate::error_tests::test_ctor!(
+    collect_invalid,
+    CollectError::invalid(1..=2, vec![0], 42, "error"),
+    remain => 1..=2,
+    collected => vec![0],
+    error => Invalid::new(42, "error")
+);
+
+crate::error_tests::test_format!(display, Invalid::new(42, Collision::new(1)), "{}", "item failed validation");
+crate::error_tests::test_source!(source, Invalid::new(42, Collision::new(1)), Collision<i32>);
+crate::error_tests::test_item_present!(error_item_provider, Invalid::new(42, "error"), Some(42));
@@ -3,8 +3,12 @@ mod error_tests;
 mod capacity_error;
 mod collection_error;
 mod collision;
+mod diagnostic;
 mod extend_error;
+mod invalid;
 mod result_collection_error;
+mod serialize;
+mod sorted_error;
 mod tuple_extend_error;
 mod unzip_error;
 
@@ -22,9 +22,9 @@ test_ctor!(
 const EXPECTED_DISPLAY_OK: &str = "Iterator error: Test error: iter error";
 const EXPECTED_DISPLAY_ERR: &str = "Iterator error: Test error: iter error; Collection error: Test error: collection error";
 const EXPECTED_DEBUG: &str =
-    r#"ResultCollectError { error: TestError("iter error"), result: Ok(HashSet<i32>), iter: core::ops::range::Range<i32> }"#;
+    r#"ResultCollectError { error: TestError("iter error"), result: Ok(HashSet<i32>), iter: core::ops::range::Range<i32>, position: None }"#;
 const EXPECTED_DEBUG_DATA: &str =
-    r#"ResultCollectErrorData { error: TestError("iter error"), result: Ok(HashSet<i32>), iter: core::ops::range::Range<i32> }"#;
+    r#"ResultCollectErrorData { error: TestError("iter error"), result: Ok(HashSet<i32>), iter: core::ops::range::Range<i32>, position: None }"#;
 
 test_format!(
     display_format_ok,
@@ -58,3 +58,68 @@ test_source!(
     ResultCollectError::new(TestError::<()>::new("iter error"), Ok::<_, TestError::<()>>(HashSet::from([1, 2, 3])), 0..0),
     TestError::<()>
 );
+
+mod map {
+    use super::*;
+
+    test_ctor!(
+        map_error,
+        ResultCollectError::new(TestError::<()>::new("iter error"), Ok::<_, ()>(HashSet::from([1, 2, 3])), 0..0)
+            .map_error(|error| error.identity),
+        error => "iter error",
+        result => Ok(HashSet::from([1, 2, 3])),
+        iter => 0..0
+    );
+    test_ctor!(
+        map_result,
+        ResultCollectError::new(TestError::<()>::new("iter error"), Ok::<_, ()>(HashSet::from([1, 2, 3])), 0..0)
+            .map_result(|result| result.map(|set| set.len()).map_err(|()| "failed")),
+        error => TestError::new("iter error"),
+        result => Ok(3),
+        iter => 0..0
+    );
+    test_ctor!(
+        map_iter,
+        ResultCollectError::new(TestError::<()>::new("iter error"), Ok::<_, ()>(HashSet::from([1, 2, 3])), 0..2)
+            .map_iter(Iterator::count),
+        error => TestError::new("iter error"),
+        result => Ok(HashSet::from([1, 2, 3])),
+        iter => 2
+    );
+}
+
+mod into_iter {
+    use collect_failable::TryFromIterator;
+
+    use super::*;
+    use crate::error_tests::test_into_iter;
+
+    const DATA: [Result<i32, &str>; 4] = [Ok(1), Ok(2), Err("invalid"), Ok(3)];
+
+    test_into_iter!(
+        collected,
+        ResultCollectError::new("invalid", Ok::<_, HashSet<i32>>(HashSet::from([1, 2])), [Ok(3)].into_iter()),
+        vec![Ok(1), Ok(2), Err("invalid"), Ok(3)]
+    );
+    test_into_iter!(
+        collection_failed,
+        ResultCollectError::new("invalid", Err::<HashSet<i32>, _>(vec![1, 2]), [Ok(3)].into_iter()),
+        vec![Ok(1), Ok(2), Err("invalid"), Ok(3)]
+    );
+    test_into_iter!(
+        hash_set,
+        Result::<HashSet<i32>, _>::try_from_iter(DATA).expect_err("should fail"),
+        DATA.to_vec()
+    );
+    test_into_iter!(
+        array_underflow,
+        Result::<[i32; 3], _>::try_from_iter(DATA).expect_err("should fail"),
+        DATA.to_vec()
+    );
+
+    #[test]
+    fn array_underflow_collection_failed() {
+        let err = Result::<[i32; 3], _>::try_from_iter(DATA).expect_err("should fail");
+        assert!(err.result.is_err(), "array should underflow");
+    }
+}
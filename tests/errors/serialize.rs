@@ -0,0 +1,54 @@
+use collect_failable::errors::capacity::CapacityError;
+use collect_failable::errors::sorted::SortedError;
+use collect_failable::errors::types::SizeHint;
+use collect_failable::errors::{CollectError, Collision, ExtendError, Invalid};
+
+use crate::error_tests::INVALID_ITER;
+
+macro_rules! test_json {
+    ($name:ident, $value:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let json = serde_json::to_string(&$value).expect("should serialize");
+            assert_eq!(json, $expected);
+        }
+    };
+}
+
+test_json!(collision, Collision::new(1), r#"{"item":1}"#);
+
+test_json!(
+    capacity_bounds,
+    CapacityError::<i32>::bounds(SizeHint::at_most(2), SizeHint::unbounded(3)),
+    r#"{"capacity":{"lower":0,"upper":2},"kind":{"Bounds":{"hint":{"lower":3,"upper":null}}}}"#
+);
+test_json!(
+    capacity_underflow,
+    CapacityError::<i32>::underflow(SizeHint::exact(3), 1),
+    r#"{"capacity":{"lower":3,"upper":3},"kind":{"Underflow":{"count":1}}}"#
+);
+test_json!(
+    capacity_overflow,
+    CapacityError::overflow(SizeHint::at_most(2), 3),
+    r#"{"capacity":{"lower":0,"upper":2},"kind":{"Overflow":{"overflow":3}}}"#
+);
+
+test_json!(sorted, SortedError::not_sorted(1), r#"{"item":1,"kind":"NotSorted"}"#);
+test_json!(invalid, Invalid::new(-1, "not positive"), r#"{"item":-1,"error":"not positive"}"#);
+
+test_json!(
+    collect_error,
+    CollectError::collision(INVALID_ITER, vec![1, 2], 1),
+    r#"{"error":{"item":1},"collected":2,"position":null}"#
+);
+test_json!(
+    collect_error_position,
+    CollectError::collision(INVALID_ITER, vec![1, 2], 1).with_position(2),
+    r#"{"error":{"item":1},"collected":2,"position":2}"#
+);
+test_json!(extend_error, ExtendError::collision(INVALID_ITER, 1), r#"{"error":{"item":1},"position":null}"#);
+test_json!(
+    extend_error_position,
+    ExtendError::collision(INVALID_ITER, 1).with_position(1),
+    r#"{"error":{"item":1},"position":1}"#
+);
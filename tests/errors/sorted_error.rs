@@ -0,0 +1,32 @@
+use collect_failable::errors::collision::Collision;
+use collect_failable::errors::sorted::{SortedError, SortedErrorKind};
+
+crate::error_tests::test_ctor!(
+    collision,
+    SortedError::collision(42),
+    item => 42,
+    kind => SortedErrorKind::Collision
+);
+
+crate::error_tests::test_ctor!(
+    not_sorted,
+    SortedError::not_sorted(42),
+    item => 42,
+    kind => SortedErrorKind::NotSorted
+);
+
+crate::error_tests::test_ctor!(
+    from_collision,
+    SortedError::from(Collision::new(42)),
+    item => 42,
+    kind => SortedErrorKind::Collision
+);
+
+crate::error_tests::test_format!(
+    display,
+    SortedError::not_sorted(42),
+    "{}",
+    "Sorted collection failed: item out of order"
+);
+
+crate::error_tests::test_item_present!(error_item_provider, SortedError::not_sorted(42), Some(42));
@@ -12,10 +12,10 @@ mod format {
     use crate::error_tests::test_format;
 
     const EXPECTED_DEBUG: &str =
-        r#"TupleExtendError { error: TestError("test"), pending: i32, remaining: core::ops::range::RangeInclusive<i32> }"#;
+        r#"TupleExtendError { error: TestError("test"), pending: i32, remaining: core::ops::range::RangeInclusive<i32>, position: None }"#;
     const EXPECTED_DISPLAY: &str = "Error while extending collection: Test error: test";
     const EXPECTED_DEBUG_DATA: &str =
-        r#"TupleExtendErrorData { error: TestError("test"), pending: i32, remaining: core::ops::range::RangeInclusive<i32> }"#;
+        r#"TupleExtendErrorData { error: TestError("test"), pending: i32, remaining: core::ops::range::RangeInclusive<i32>, position: None }"#;
 
     test_format!(debug, TupleExtendError::new(TEST_ERROR, PENDING_VALUE, REMAIN_ITER), "{:?}", EXPECTED_DEBUG);
 
@@ -54,3 +54,69 @@ mod ctors {
 
 test_source!(source, TupleExtendError::new(TEST_ERROR, PENDING_VALUE, REMAIN_ITER), TestError::<i32>);
 test_source!(source_data, TupleExtendError::new(TEST_ERROR, PENDING_VALUE, REMAIN_ITER).into_data(), TestError::<i32>);
+
+mod map {
+    use super::*;
+    use crate::error_tests::test_ctor;
+
+    test_ctor!(
+        map_error,
+        TupleExtendError::new(TEST_ERROR, PENDING_VALUE, REMAIN_ITER).map_error(|error| error.identity),
+        error => "test",
+        pending => PENDING_VALUE,
+        remaining => REMAIN_ITER
+    );
+    test_ctor!(
+        map_pending,
+        TupleExtendError::new(TEST_ERROR, PENDING_VALUE, REMAIN_ITER).map_pending(|item| item * 2),
+        error => TEST_ERROR,
+        pending => Some(40),
+        remaining => REMAIN_ITER
+    );
+    test_ctor!(
+        map_remaining,
+        TupleExtendError::new(TEST_ERROR, PENDING_VALUE, REMAIN_ITER).map_remaining(Iterator::count),
+        error => TEST_ERROR,
+        pending => PENDING_VALUE,
+        remaining => 3
+    );
+}
+
+mod into_iter {
+    use collect_failable::TryExtend;
+    use collect_failable::collections::SortedVec;
+
+    #[test]
+    fn left() {
+        let mut vecs = (SortedVec::new(), SortedVec::new());
+        let err = vecs.try_extend([(1, 10), (0, 20), (2, 30)]).expect_err("should fail").left().expect("should fail on left side");
+        assert_eq!(err.into_left_iter().collect::<Vec<_>>(), [(0, 20), (2, 30)]);
+    }
+
+    #[test]
+    fn right() {
+        let mut vecs = (SortedVec::new(), SortedVec::new());
+        let err = vecs.try_extend([(1, 10), (2, 0), (3, 30)]).expect_err("should fail").right().expect("should fail on right side");
+        let (rejected, remaining) = err.into_right_parts();
+        assert_eq!(rejected, Some(0), "the rejected item should be returned");
+        assert_eq!(remaining.collect::<Vec<_>>(), [(3, 30)]);
+        assert_eq!(*vecs.0, [1, 2]);
+    }
+
+    #[test]
+    fn left_mapped() {
+        let mut vecs = (SortedVec::new(), SortedVec::new());
+        let err = vecs.try_extend([(1, 10), (0, 20), (2, 30)]).expect_err("should fail").left().expect("should fail on left side");
+        let err = err.map_left_items(|a| a.to_string(), |b| b * 2);
+        assert_eq!(err.into_left_iter().collect::<Vec<_>>(), [("0".to_string(), 40), ("2".to_string(), 60)]);
+    }
+
+    #[test]
+    fn right_mapped() {
+        let mut vecs = (SortedVec::new(), SortedVec::new());
+        let err = vecs.try_extend([(1, 10), (2, 0), (3, 30)]).expect_err("should fail").right().expect("should fail on right side");
+        let (rejected, remaining) = err.map_right_items(|a| a.to_string(), |b| b * 2).into_right_parts();
+        assert_eq!(rejected, Some(0));
+        assert_eq!(remaining.collect::<Vec<_>>(), [("3".to_string(), 60)]);
+    }
+}
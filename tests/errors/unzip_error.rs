@@ -32,8 +32,8 @@ mod format {
     use super::*;
     use crate::error_tests::test_format;
 
-    const EXPECTED_DEBUG: &str = r#"UnzipError { error: Collision { item: 1 }, failed: HashSet<i32>, partial: HashSet<i32>, pending: Some(i32), remaining: core::array::iter::IntoIter<(i32, i32), 1> }"#;
-    const EXPECTED_DEBUG_DATA: &str = r#"UnzipErrorData { error: Collision { item: 1 }, failed: HashSet<i32>, partial: HashSet<i32>, pending: Some(i32), remaining: core::array::iter::IntoIter<(i32, i32), 1> }"#;
+    const EXPECTED_DEBUG: &str = r#"UnzipError { error: Collision { item: 1 }, failed: HashSet<i32>, partial: HashSet<i32>, pending: Some(i32), remaining: core::array::iter::IntoIter<(i32, i32), 1>, position: None }"#;
+    const EXPECTED_DEBUG_DATA: &str = r#"UnzipErrorData { error: Collision { item: 1 }, failed: HashSet<i32>, partial: HashSet<i32>, pending: Some(i32), remaining: core::array::iter::IntoIter<(i32, i32), 1>, position: None }"#;
     const EXPECTED_DISPLAY: &str = "Failed while unzipping collection: item collision";
 
     test_format!(debug, sample_error(), "{:?}", EXPECTED_DEBUG);
@@ -73,3 +73,105 @@ mod ctors {
 
 test_source!(source, sample_error(), Collision<i32>);
 test_source!(source_data, sample_error().into_data(), Collision<i32>);
+
+mod map {
+    use super::*;
+    use crate::error_tests::test_ctor;
+
+    test_ctor!(
+        map_error,
+        sample_error().map_error(|error| error.item),
+        error => 1,
+        failed => failed_collection(),
+        partial => partial_collection(),
+        pending => PENDING_ITEM
+    );
+    test_ctor!(
+        map_failed,
+        sample_error().map_failed(|failed| failed.len()),
+        error => COLLISION_ERROR,
+        failed => 2,
+        partial => partial_collection()
+    );
+    test_ctor!(
+        map_partial,
+        sample_error().map_partial(|partial| partial.len()),
+        error => COLLISION_ERROR,
+        failed => failed_collection(),
+        partial => 2
+    );
+    test_ctor!(
+        map_pending,
+        sample_error().map_pending(|item| item.to_string()),
+        pending => Some("30".to_string())
+    );
+    test_ctor!(
+        map_remaining,
+        sample_error().map_remaining(Iterator::count),
+        remaining => 1
+    );
+}
+
+mod into_iter {
+    use collect_failable::TryUnzip;
+    use collect_failable::collections::SortedVec;
+    use indexmap::IndexSet;
+
+    use super::*;
+
+    const LEFT_FAILS: [(i32, i32); 4] = [(1, 10), (2, 20), (0, 30), (3, 40)];
+    const RIGHT_FAILS: [(i32, i32); 4] = [(1, 10), (2, 20), (3, 5), (4, 40)];
+
+    #[test]
+    fn left() {
+        let err = LEFT_FAILS.into_iter().try_unzip::<SortedVec<_>, SortedVec<_>>().expect_err("should fail");
+        let err = err.left().expect("should fail on left side");
+        assert_eq!(err.into_left_iter().collect::<Vec<_>>(), LEFT_FAILS);
+    }
+
+    #[test]
+    fn right() {
+        let err = RIGHT_FAILS.into_iter().try_unzip::<SortedVec<_>, SortedVec<_>>().expect_err("should fail");
+        let err = err.right().expect("should fail on right side");
+        assert_eq!(err.into_right_iter().collect::<Vec<_>>(), RIGHT_FAILS);
+    }
+
+    #[test]
+    fn either() {
+        for pairs in [LEFT_FAILS, RIGHT_FAILS] {
+            let err = pairs.into_iter().try_unzip::<SortedVec<_>, SortedVec<_>>().expect_err("should fail");
+            let recovered = err.map_either(UnzipError::into_left_iter, UnzipError::into_right_iter);
+            assert_eq!(recovered.collect::<Vec<_>>(), pairs);
+        }
+    }
+
+    #[test]
+    fn left_mapped() {
+        let err = LEFT_FAILS.into_iter().try_unzip::<SortedVec<_>, SortedVec<_>>().expect_err("should fail");
+        let err = err.left().expect("should fail on left side");
+        let err = err.map_left_items::<_, _, Vec<_>, Vec<_>, _, _>(|a| a.to_string(), |b| b * 2);
+
+        assert_eq!(err.failed, ["1", "2"]);
+        assert_eq!(err.partial, [20, 40]);
+        assert_eq!(err.pending, Some(60));
+        assert_eq!(err.into_left_iter().collect::<Vec<_>>(), LEFT_FAILS.map(|(a, b)| (a.to_string(), b * 2)));
+    }
+
+    #[test]
+    fn right_mapped() {
+        let err = RIGHT_FAILS.into_iter().try_unzip::<SortedVec<_>, SortedVec<_>>().expect_err("should fail");
+        let err = err.right().expect("should fail on right side");
+        let err = err.map_right_items::<_, _, Vec<_>, Vec<_>, _, _>(|a| a.to_string(), |b| b * 2);
+
+        assert_eq!(err.failed, [20, 40]);
+        assert_eq!(err.partial, ["1", "2", "3"], "the first item of the failed pair was already added");
+        assert_eq!(err.into_right_iter().collect::<Vec<_>>(), RIGHT_FAILS.map(|(a, b)| (a.to_string(), b * 2)));
+    }
+
+    #[test]
+    fn single_item_collections() {
+        let err = [(1, 10), (1, 20)].into_iter().try_unzip::<IndexSet<_>, Vec<_>>().expect_err("should fail");
+        let err = err.left().expect("should fail on left side");
+        assert_eq!(err.into_left_iter().collect::<Vec<_>>(), [(1, 10), (1, 20)]);
+    }
+}
@@ -24,3 +24,17 @@ fn post_inc() {
     assert_eq!(index.post_inc(), 0);
     assert_eq!(index, 1);
 }
+
+#[test]
+fn array_index_pre_dec() {
+    let mut index = ArrayIndex::<3>::from(2);
+
+    assert_eq!(index.try_pre_dec(), Some(1));
+    assert_eq!(*index, 1);
+
+    assert_eq!(index.try_pre_dec(), Some(0));
+    assert_eq!(*index, 0);
+
+    assert_eq!(index.try_pre_dec(), None);
+    assert_eq!(*index, 0);
+}
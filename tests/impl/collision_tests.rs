@@ -1,7 +1,7 @@
 use crate::collection_tests::try_extend_one;
 use assert_unordered::assert_eq_unordered;
 use collect_failable::errors::{CollectError, Collision, ErrorItemProvider, ExtendError};
-use collect_failable::{TryExtend, TryExtendOne, TryExtendSafe, TryFromIterator};
+use collect_failable::{TryExtend, TryExtendInPlace, TryExtendOne, TryExtendSafe, TryFromIterator};
 use tap::Pipe;
 
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
@@ -103,17 +103,17 @@ macro_rules! generate_collision_tests {
             #[test]
             fn try_collect_no_collision() {
                 let collection = <$type>::try_from_iter($params.values).expect("should succeed");
-                assert_eq!(collection, <$type>::from($params.values), "should match");
+                assert_eq!(collection, <$type>::from_iter($params.values), "should match");
             }
 
             #[test]
             fn try_extend_safe_collision_with_collection() {
                 let data = $params.other_collision;
-                let mut collection = <$type>::from($params.values);
+                let mut collection = <$type>::from_iter($params.values);
 
                 let err = collection.try_extend_safe(data.add).expect_err("should collide");
 
-                assert_eq!(collection, <$type>::from($params.values), "collection should be unchanged");
+                assert_eq!(collection, <$type>::from_iter($params.values), "collection should be unchanged");
 
                 super::check_collision_error(&err, &data, &data.collected().collect::<$type>());
 
@@ -148,12 +148,12 @@ macro_rules! generate_collision_tests {
 
                 collection.try_extend_safe($params.values).expect("should succeed");
 
-                assert_eq!(collection, <$type>::from($params.values), "should match");
+                assert_eq!(collection, <$type>::from_iter($params.values), "should match");
             }
 
             #[test]
             fn try_extend_safe_valid_extension() {
-                let mut collection = <$type>::from($params.values);
+                let mut collection = <$type>::from_iter($params.values);
 
                 collection.try_extend_safe($params.valid_extension).expect("should succeed");
 
@@ -161,12 +161,53 @@ macro_rules! generate_collision_tests {
                 assert_eq!(collection, expected, "should match total");
             }
 
+            #[test]
+            fn try_extend_in_place_collision_with_collection() {
+                let data = $params.other_collision;
+                let mut collection = <$type>::from_iter($params.values);
+
+                let err = collection.try_extend_in_place(data.add).expect_err("should collide");
+
+                assert_eq!(collection, <$type>::from_iter($params.values), "collection should be unchanged");
+
+                super::check_collision_error(&err, &data, &data.collected().collect::<$type>());
+                assert_eq!(err.position, Some(data.collide_pos));
+            }
+
+            #[test]
+            fn try_extend_in_place_collision_within_iter() {
+                let data = $params.self_collision;
+                let mut collection = <$type>::new();
+
+                let err = collection.try_extend_in_place(data.add).expect_err("should collide");
+
+                assert!(collection.is_empty(), "collection should be unchanged");
+
+                super::check_collision_error(&err, &data, &data.collected().collect::<$type>());
+
+                assert_eq_unordered!(
+                    data.add.into_iter().collect::<Vec<_>>(),
+                    err.into_iter().collect::<Vec<_>>(),
+                    "all added items should be recovered"
+                );
+            }
+
+            #[test]
+            fn try_extend_in_place_valid_extension() {
+                let mut collection = <$type>::from_iter($params.values);
+
+                collection.try_extend_in_place($params.valid_extension).expect("should succeed");
+
+                let expected = $params.values.into_iter().chain($params.valid_extension).collect::<$type>();
+                assert_eq!(collection, expected, "should match total");
+            }
+
             #[test]
             fn try_extend_collision_with_collection() {
                 let base = $params.values;
                 let data = $params.other_collision;
 
-                let mut collection = <$type>::from(base);
+                let mut collection = <$type>::from_iter(base);
 
                 let err = collection.try_extend(data.add).expect_err("should collide");
 
@@ -216,7 +257,7 @@ macro_rules! generate_collision_tests {
                 let data = $params.values;
                 let mut collection = <$type>::new();
                 collection.try_extend(data).expect("should succeed");
-                assert_eq!(collection, <$type>::from(data), "should match");
+                assert_eq!(collection, <$type>::from_iter(data), "should match");
             }
 
             mod try_extend_one {
@@ -224,14 +265,14 @@ macro_rules! generate_collision_tests {
 
                 try_extend_one!(
                     valid,
-                    <$type>::from($params.values),
+                    <$type>::from_iter($params.values),
                     $params.valid_extension[0],
                     Ok(<$type>::from_iter($params.values.into_iter().chain(std::iter::once($params.valid_extension[0]))))
                 );
 
                 try_extend_one!(
                     collision,
-                    <$type>::from($params.values),
+                    <$type>::from_iter($params.values),
                     $params.other_collision.colliding_item().copied().unwrap(),
                     Err($params.other_collision.colliding_error())
                 );
@@ -244,8 +285,80 @@ generate_collision_tests!(maps_hash_map, HashMap<_, _>, MAP_PARAMS);
 generate_collision_tests!(maps_btree_map, BTreeMap<_, _>, MAP_PARAMS);
 generate_collision_tests!(maps_hashbrown_map, HashBrownMap<_, _>, MAP_PARAMS);
 generate_collision_tests!(maps_index_map, indexmap::IndexMap<_, _>, MAP_PARAMS);
+generate_collision_tests!(maps_linked_hash_map, hashlink::LinkedHashMap<_, _>, MAP_PARAMS);
+generate_collision_tests!(maps_order_map, ordermap::OrderMap<_, _>, MAP_PARAMS);
 
 generate_collision_tests!(sets_hash_set, HashSet<_>, SET_PARAMS);
 generate_collision_tests!(sets_btree_set, BTreeSet<_>, SET_PARAMS);
 generate_collision_tests!(sets_hashbrown_set, HashBrownSet<_>, SET_PARAMS);
 generate_collision_tests!(sets_index_set, indexmap::IndexSet<_>, SET_PARAMS);
+generate_collision_tests!(sets_linked_hash_set, hashlink::LinkedHashSet<_>, SET_PARAMS);
+generate_collision_tests!(sets_order_set, ordermap::OrderSet<_>, SET_PARAMS);
+
+#[test]
+fn index_map_try_extend_in_place_preserves_order() {
+    let mut map = indexmap::IndexMap::from([(3, 'c'), (1, 'a'), (2, 'b')]);
+
+    let err = map.try_extend_in_place([(5, 'e'), (4, 'd'), (6, 'f'), (1, 'z')]).expect_err("should collide");
+
+    assert_eq!(map.into_iter().collect::<Vec<_>>(), [(3, 'c'), (1, 'a'), (2, 'b')], "order should be unchanged");
+    assert_eq!(
+        err.into_data().collected.into_iter().collect::<Vec<_>>(),
+        [(5, 'e'), (4, 'd'), (6, 'f')],
+        "order should be preserved"
+    );
+}
+
+#[test]
+fn index_map_try_extend_in_place_without_clone_keys() {
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct Key(i32);
+
+    let mut map = indexmap::IndexMap::from([(Key(1), 'a')]);
+
+    let err = map.try_extend_in_place([(Key(2), 'b'), (Key(1), 'z')]).expect_err("should collide");
+
+    assert_eq!(err.into_data().collected, indexmap::IndexMap::from([(Key(2), 'b')]));
+    assert_eq!(map, indexmap::IndexMap::from([(Key(1), 'a')]), "map should be unchanged");
+}
+
+macro_rules! test_recovery_preserves_order {
+    ($name:ident, $type:ty) => {
+        mod $name {
+            use super::*;
+
+            const ITEMS: [(i32, char); 5] = [(5, 'e'), (4, 'd'), (6, 'f'), (5, 'z'), (7, 'g')];
+
+            #[test]
+            fn collect() {
+                let err = <$type>::try_from_iter(ITEMS).expect_err("should collide");
+
+                assert_eq!(err.into_iter().collect::<Vec<_>>(), [(5, 'z'), (5, 'e'), (4, 'd'), (6, 'f'), (7, 'g')]);
+            }
+
+            #[test]
+            fn extend_safe() {
+                let mut map = <$type>::from_iter([(3, 'c'), (1, 'a')]);
+
+                let err = map.try_extend_safe(ITEMS).expect_err("should collide");
+
+                assert_eq!(err.into_iter().collect::<Vec<_>>(), [(5, 'z'), (5, 'e'), (4, 'd'), (6, 'f'), (7, 'g')]);
+                assert_eq!(map.into_iter().collect::<Vec<_>>(), [(3, 'c'), (1, 'a')], "order should be unchanged");
+            }
+
+            #[test]
+            fn extend_in_place() {
+                let mut map = <$type>::from_iter([(3, 'c'), (1, 'a')]);
+
+                let err = map.try_extend_in_place(ITEMS).expect_err("should collide");
+
+                assert_eq!(err.into_iter().collect::<Vec<_>>(), [(5, 'z'), (5, 'e'), (4, 'd'), (6, 'f'), (7, 'g')]);
+                assert_eq!(map.into_iter().collect::<Vec<_>>(), [(3, 'c'), (1, 'a')], "order should be unchanged");
+            }
+        }
+    };
+}
+
+test_recovery_preserves_order!(index_map_recovery_order, indexmap::IndexMap<_, _>);
+test_recovery_preserves_order!(linked_hash_map_recovery_order, hashlink::LinkedHashMap<_, _>);
+test_recovery_preserves_order!(order_map_recovery_order, ordermap::OrderMap<_, _>);
@@ -0,0 +1,133 @@
+use collect_failable::collections::HashTableWith;
+use collect_failable::errors::Collision;
+use collect_failable::{TryCollectEx, TryExtend, TryExtendOne, TryExtendSafe};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Record {
+    id: u32,
+    name: &'static str,
+}
+
+const fn record(id: u32, name: &'static str) -> Record {
+    Record { id, name }
+}
+
+/// A deliberately weak hash, so that distinct ids share buckets and `eq` decides collisions
+fn hash(record: &Record) -> u64 {
+    u64::from(record.id % 2)
+}
+
+fn same_id(a: &Record, b: &Record) -> bool {
+    a.id == b.id
+}
+
+type Table = HashTableWith<Record, fn(&Record) -> u64, fn(&Record, &Record) -> bool>;
+
+fn table<const N: usize>(records: [Record; N]) -> Table {
+    let mut table = Table::new(hash, same_id);
+    table.try_extend(records).expect("should not collide");
+    table
+}
+
+fn sorted(table: impl IntoIterator<Item = Record>) -> Vec<Record> {
+    let mut records: Vec<_> = table.into_iter().collect();
+    records.sort_by_key(|record| record.id);
+    records
+}
+
+mod collect {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let table = [record(1, "a"), record(2, "b"), record(3, "c")]
+            .into_iter()
+            .try_collect_table(hash, same_id)
+            .expect("should not collide");
+
+        assert_eq!(sorted(table), [record(1, "a"), record(2, "b"), record(3, "c")]);
+    }
+
+    #[test]
+    fn collision() {
+        let err = [record(1, "a"), record(3, "b"), record(1, "c"), record(4, "d")]
+            .into_iter()
+            .try_collect_table(hash, same_id)
+            .expect_err("should collide");
+
+        assert_eq!(err.error, Collision::new(record(1, "c")), "rejected record should be returned");
+        assert_eq!(err.position, Some(2));
+
+        let data = err.into_data();
+        assert_eq!(sorted(data.collected), [record(1, "a"), record(3, "b")]);
+        assert_eq!(data.remain.collect::<Vec<_>>(), [record(4, "d")]);
+    }
+}
+
+mod extend {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let mut table = table([record(1, "a")]);
+
+        table.try_extend([record(2, "b"), record(3, "c")]).expect("should not collide");
+
+        assert_eq!(sorted(table), [record(1, "a"), record(2, "b"), record(3, "c")]);
+    }
+
+    #[test]
+    fn collision() {
+        let mut table = table([record(1, "a")]);
+
+        let err = table.try_extend([record(2, "b"), record(1, "c"), record(3, "d")]).expect_err("should collide");
+
+        assert_eq!(err.error, Collision::new(record(1, "c")));
+        assert_eq!(err.position, Some(1));
+        assert_eq!(err.into_data().remain.collect::<Vec<_>>(), [record(3, "d")]);
+        assert_eq!(sorted(table), [record(1, "a"), record(2, "b")], "items before the collision should be added");
+    }
+
+    #[test]
+    fn safe_valid() {
+        let mut table = table([record(1, "a")]);
+
+        table.try_extend_safe([record(2, "b"), record(3, "c")]).expect("should not collide");
+
+        assert_eq!(sorted(table), [record(1, "a"), record(2, "b"), record(3, "c")]);
+    }
+
+    #[test]
+    fn safe_collision_with_table() {
+        let mut table = table([record(1, "a")]);
+
+        let err = table.try_extend_safe([record(2, "b"), record(1, "c"), record(3, "d")]).expect_err("should collide");
+
+        assert_eq!(err.error, Collision::new(record(1, "c")));
+        assert_eq!(err.position, Some(1));
+        assert_eq!(sorted(err.into_data().collected), [record(2, "b")]);
+        assert_eq!(sorted(table), [record(1, "a")], "table should be unchanged");
+    }
+
+    #[test]
+    fn safe_collision_within_iter() {
+        let mut table = table([record(1, "a")]);
+
+        let err = table.try_extend_safe([record(2, "b"), record(2, "c")]).expect_err("should collide");
+
+        assert_eq!(err.error, Collision::new(record(2, "c")));
+        assert_eq!(sorted(table), [record(1, "a")], "table should be unchanged");
+    }
+
+    #[test]
+    fn one() {
+        let mut table = table([record(1, "a")]);
+
+        table.try_extend_one(record(3, "b")).expect("should not collide");
+        let err = table.try_extend_one(record(1, "c")).expect_err("should collide");
+
+        assert_eq!(err, Collision::new(record(1, "c")));
+        assert!(table.contains(&record(3, "z")));
+        assert_eq!(table.len(), 2);
+    }
+}
@@ -5,12 +5,22 @@ mod arrayvec;
 mod collection_tests;
 mod collision_tests;
 mod ensure_empty;
+mod hash_table;
+mod min_len;
 mod partial_array;
+mod position;
+mod prealloc;
+mod size_hint;
 
 mod result;
 mod sets;
+mod slices;
+mod sorted;
+mod sorted_collections;
+mod try_extend_cloned;
 mod try_unzip;
 mod tuples;
+mod validated;
 
 #[path = "../utils/mod.rs"]
 mod utils;
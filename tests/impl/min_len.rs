@@ -0,0 +1,102 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::collection_tests::{recover_iter_data, try_collect};
+use crate::utils::panics;
+
+use collect_failable::collections::{MinLen, NonEmpty};
+use collect_failable::errors::capacity::{CapacityError, FixedCap};
+use collect_failable::errors::{Collision, WrapperError};
+use collect_failable::{TryCollectEx, TryExtendOne, TryExtendSafe, TryFromIterator};
+
+use indexmap::IndexMap;
+use size_hinter::{InvalidIterator, SizeHint, SizeHinter};
+
+type MinVec = MinLen<Vec<i32>, 2>;
+
+fn min_vec<const N: usize>(items: [i32; N]) -> MinVec {
+    MinVec::try_new(items.to_vec()).expect("should be long enough")
+}
+
+try_collect!(valid, MinVec, 1..=3, Ok(min_vec([1, 2, 3])));
+try_collect!(exact, MinVec, 1..=2, Ok(min_vec([1, 2])));
+try_collect!(
+    bounds,
+    MinVec,
+    1..=1,
+    Err(WrapperError::Wrapper(CapacityError::bounds(SizeHint::unbounded(2), SizeHint::exact(1))))
+);
+try_collect!(
+    underflow,
+    MinVec,
+    (1..=1).hide_size(),
+    Err(WrapperError::Wrapper(CapacityError::underflow(SizeHint::unbounded(2), 1)))
+);
+try_collect!(collision, MinLen<HashSet<i32>, 2>, [1, 1], Err(WrapperError::Inner(Collision::new(1))));
+
+recover_iter_data!(recover_underflow, MinVec, (1..=1).hide_size(), vec![1], vec![1]);
+
+panics!(invalid_iter, MinVec::try_from_iter(InvalidIterator::DEFAULT), "Invalid size hint");
+
+try_collect!(
+    non_empty_btree_map,
+    NonEmpty<BTreeMap<i32, i32>>,
+    [(1, 2)],
+    Ok(NonEmpty::<BTreeMap<_, _>>::try_new(BTreeMap::from([(1, 2)])).expect("should not be empty"))
+);
+try_collect!(
+    non_empty_index_map,
+    NonEmpty<IndexMap<i32, i32>>,
+    std::iter::empty(),
+    Err(WrapperError::Wrapper(CapacityError::bounds(SizeHint::unbounded(1), SizeHint::ZERO)))
+);
+
+#[test]
+fn try_new() {
+    assert_eq!(MinVec::try_new(vec![1]), Err(vec![1]));
+    assert_eq!(MinVec::try_new(vec![1, 2]).map(MinLen::into_inner), Ok(vec![1, 2]));
+}
+
+#[test]
+fn extend() {
+    let mut items = min_vec([1, 2]);
+    items.extend([3]);
+    assert_eq!(*items, [1, 2, 3]);
+    assert_eq!(items.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn try_extend_forwards() {
+    let mut set = NonEmpty::<HashSet<i32>>::try_new(HashSet::from([1])).expect("should not be empty");
+
+    [2].into_iter().try_collect_into(&mut set).expect("should not collide");
+    let err = set.try_extend_safe([3, 1]).expect_err("should collide");
+    assert_eq!(err.error, Collision::new(1));
+    let err = set.try_extend_one(2).expect_err("should collide");
+    assert_eq!(err, Collision::new(2));
+
+    assert_eq!(*set, HashSet::from([1, 2]));
+}
+
+#[test]
+fn capacity() {
+    assert_eq!(MinVec::CAP, SizeHint::unbounded(2));
+}
+
+mod nonempty_crate {
+    use super::*;
+
+    try_collect!(valid, nonempty::NonEmpty<i32>, 1..=3, Ok(nonempty::nonempty![1, 2, 3]));
+    try_collect!(
+        underflow,
+        nonempty::NonEmpty<i32>,
+        std::iter::empty().hide_size(),
+        Err(CapacityError::underflow(SizeHint::unbounded(1), 0))
+    );
+}
+
+mod vec1_crate {
+    use super::*;
+
+    try_collect!(valid, vec1::Vec1<i32>, 1..=3, Ok(vec1::vec1![1, 2, 3]));
+    try_collect!(bounds, vec1::Vec1<i32>, std::iter::empty(), Err(CapacityError::bounds(SizeHint::unbounded(1), SizeHint::ZERO)));
+}
@@ -1,6 +1,7 @@
 use collect_failable::errors::capacity::{CapacityError, FixedCap, RemainingCap};
-use collect_failable::errors::partial_array::{IntoArrayError, PartialArray};
+use collect_failable::errors::partial_array::{IntoArrayError, Iter, IterMut, PartialArray};
 use collect_failable::errors::types::SizeHint;
+use size_hinter::SizeHinter;
 
 type Array = [i32; 5];
 type TestPartialArray = PartialArray<i32, 5>;
@@ -25,7 +26,7 @@ fn try_push() {
     assert_eq!(partial.try_push(4), Ok(&mut 4));
     assert_eq!(partial.try_push(5), Ok(&mut 5));
     assert_eq!(partial.try_push(6), Err(6));
-    assert_eq!(partial[..], [1, 2, 3, 4, 5]);
+    assert_eq!(partial, [1, 2, 3, 4, 5][..]);
 }
 
 mod drop {
@@ -140,3 +141,186 @@ fn capacity() {
     });
     assert_eq!(partial.remaining_cap(), SizeHint::at_most(0));
 }
+
+fn from_slice(slice: &[i32]) -> TestPartialArray {
+    let mut partial = TestPartialArray::new();
+    partial.extend_from_slice(slice).expect("should fit");
+    partial
+}
+
+#[test]
+fn pop() {
+    let mut partial = from_slice(&[1, 2]);
+
+    assert_eq!(partial.pop(), Some(2));
+    assert_eq!(partial.pop(), Some(1));
+    assert_eq!(partial.pop(), None);
+    assert_eq!(partial.remaining_cap(), SizeHint::at_most(5));
+}
+
+mod truncate {
+    use super::*;
+
+    #[test]
+    fn shorter() {
+        let mut partial = from_slice(&[1, 2, 3]);
+        partial.truncate(1);
+        assert_eq!(partial, [1][..]);
+    }
+
+    #[test]
+    fn longer() {
+        let mut partial = from_slice(&[1, 2, 3]);
+        partial.truncate(4);
+        assert_eq!(partial, [1, 2, 3][..]);
+    }
+
+    #[test]
+    fn clear() {
+        let mut partial = from_slice(&[1, 2, 3]);
+        partial.clear();
+        assert!(partial.is_empty());
+    }
+
+    #[test]
+    fn drops_items() {
+        let (counters, viewers) = dropcount::new_vec(4);
+
+        let mut partial = PartialArray::<_, 5>::new();
+        counters.into_iter().for_each(|counter| _ = partial.try_push(counter));
+        partial.truncate(1);
+
+        viewers.iter().skip(1).for_each(|viewer| assert_eq!(viewer.get(), 1, "truncated items should be dropped once"));
+        assert_eq!(viewers[0].get(), 0, "kept item should not be dropped");
+
+        drop(partial);
+        viewers.iter().for_each(|viewer| assert_eq!(viewer.get(), 1, "Item should be dropped once"));
+    }
+}
+
+#[test]
+fn iter() {
+    let partial = from_slice(&[1, 2, 3]);
+    let iter: Iter<'_, i32> = partial.iter();
+
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [&3, &2, &1]);
+    assert_eq!((&partial).into_iter().collect::<Vec<_>>(), [&1, &2, &3]);
+}
+
+#[test]
+fn iter_mut() {
+    let mut partial = from_slice(&[1, 2, 3]);
+    let iter: IterMut<'_, i32> = partial.iter_mut();
+    iter.for_each(|item| *item *= 2);
+    (&mut partial).into_iter().for_each(|item| *item += 1);
+
+    assert_eq!(partial, [3, 5, 7][..]);
+}
+
+mod extend_from_slice {
+    use super::*;
+
+    #[test]
+    fn fits() {
+        let mut partial = from_slice(&[1, 2]);
+        partial.extend_from_slice(&[3, 4, 5]).expect("should fit");
+        assert_eq!(partial, [1, 2, 3, 4, 5][..]);
+    }
+
+    #[test]
+    fn too_long() {
+        let mut partial = from_slice(&[1, 2]);
+        let err = partial.extend_from_slice(&[3, 4, 5, 6]).expect_err("should not fit");
+
+        assert_eq!(err, CapacityError::bounds(SizeHint::at_most(3), SizeHint::exact(4)));
+        assert_eq!(partial, [1, 2][..], "should be unchanged on error");
+    }
+}
+
+mod traits {
+    use super::*;
+    use std::hash::{BuildHasher, RandomState};
+
+    #[test]
+    fn clone() {
+        let partial = from_slice(&[1, 2, 3]);
+        assert_eq!(partial.clone(), partial);
+    }
+
+    #[test]
+    fn eq() {
+        let mut smaller = PartialArray::<i32, 3>::new();
+        smaller.extend_from_slice(&[1, 2]).expect("should fit");
+
+        assert_eq!(from_slice(&[1, 2]), smaller, "different capacities should compare by items");
+        assert_ne!(from_slice(&[1, 2]), from_slice(&[1, 3]));
+    }
+
+    #[test]
+    fn hash() {
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(from_slice(&[1, 2])), state.hash_one(from_slice(&[1, 2])));
+    }
+}
+
+mod try_into_array_or_else {
+    use super::*;
+
+    #[test]
+    fn full() {
+        let array = from_slice(&[1, 2, 3, 4, 5]).try_into_array_or_else(|| unreachable!("should not fill"));
+        assert_eq!(array, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn underfilled() {
+        let mut fill = 10..;
+        let array = from_slice(&[1, 2]).try_into_array_or_else(|| fill.next().expect("should be infinite"));
+        assert_eq!(array, [1, 2, 10, 11, 12]);
+    }
+}
+
+mod try_extend_safe {
+    use super::*;
+    use crate::collection_tests::try_extend_safe;
+    use collect_failable::TryExtendSafe;
+
+    try_extend_safe!(valid, from_slice(&[1]), 2..=3, Ok(from_slice(&[1, 2, 3])));
+    try_extend_safe!(
+        bounds,
+        from_slice(&[1]),
+        2..=6,
+        Err(CapacityError::bounds(SizeHint::at_most(4), SizeHint::exact(5)), TestPartialArray::new(), 2..=6)
+    );
+    try_extend_safe!(
+        overflow,
+        from_slice(&[1]),
+        (2..=7).hide_size(),
+        Err(CapacityError::overflow(SizeHint::at_most(4), 6), from_slice(&[2, 3, 4, 5]), 7..=7)
+    );
+}
+
+mod try_extend {
+    use super::*;
+    use crate::collection_tests::try_extend;
+    use collect_failable::TryExtend;
+
+    try_extend!(valid, from_slice(&[1]), 2..=3, Ok(from_slice(&[1, 2, 3])));
+    try_extend!(
+        bounds,
+        from_slice(&[1]),
+        2..=6,
+        Err(CapacityError::bounds(SizeHint::at_most(4), SizeHint::exact(5)), 2..=6)
+    );
+    try_extend!(overflow, from_slice(&[1]), (2..=7).hide_size(), Err(CapacityError::overflow(SizeHint::at_most(4), 6), 7..=7));
+}
+
+mod try_extend_one {
+    use super::*;
+    use crate::collection_tests::try_extend_one;
+    use collect_failable::TryExtendOne;
+
+    try_extend_one!(valid, from_slice(&[1]), 2, Ok(from_slice(&[1, 2])));
+    try_extend_one!(overflow, from_slice(&[1, 2, 3, 4, 5]), 6, Err(CapacityError::overflow(SizeHint::at_most(0), 6)));
+}
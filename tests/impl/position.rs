@@ -0,0 +1,97 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use arrayvec::ArrayVec;
+use collect_failable::{
+    TryCollectEx, TryExtend, TryExtendSafe, TryExtendSorted, TryFromIterator, TryFromSortedIterator, TryUnzip,
+};
+use size_hinter::SizeHinter;
+
+macro_rules! test_position {
+    ($name:ident, $expr:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let err = $expr;
+            assert_eq!(err.position, $expected, "should report the position of the failing item");
+        }
+    };
+}
+
+test_position!(hash_set_collect, HashSet::<_>::try_from_iter([1, 2, 1, 3]).expect_err("should collide"), Some(2));
+test_position!(hash_set_extend, HashSet::from([1]).try_extend([2, 3, 1]).expect_err("should collide"), Some(2));
+test_position!(hash_set_extend_safe, HashSet::from([1]).try_extend_safe([2, 1]).expect_err("should collide"), Some(1));
+test_position!(hash_map_collect, HashMap::<_, _>::try_from_iter([(1, 'a'), (1, 'b')]).expect_err("should collide"), Some(1));
+test_position!(
+    hashbrown_extend_safe,
+    hashbrown::HashMap::<_, _>::from([(1, 'a')]).try_extend_safe([(2, 'b'), (3, 'c'), (1, 'd')]).expect_err("should collide"),
+    Some(2)
+);
+test_position!(btree_set_sorted, BTreeSet::try_from_sorted_iter([1, 2, 4, 3]).expect_err("should not be sorted"), Some(3));
+test_position!(btree_set_extend_sorted, BTreeSet::from([5]).try_extend_sorted([1, 5]).expect_err("should collide"), Some(1));
+
+test_position!(array_overflow, <[i32; 2]>::try_from_iter((1..=3).hide_size()).expect_err("should overflow"), Some(2));
+test_position!(array_underflow, <[i32; 2]>::try_from_iter((1..=1).hide_size()).expect_err("should underflow"), None);
+test_position!(array_bounds, <[i32; 2]>::try_from_iter(1..=3).expect_err("should fail bounds check"), None);
+test_position!(
+    array_vec_extend,
+    ArrayVec::<i32, 3>::from_iter([1]).try_extend((2..=4).hide_size()).expect_err("should overflow"),
+    Some(2)
+);
+test_position!(
+    array_vec_extend_safe,
+    ArrayVec::<i32, 3>::from_iter([1]).try_extend_safe((2..=4).hide_size()).expect_err("should overflow"),
+    Some(2)
+);
+test_position!(boxed_slice_overflow, <Box<[i32; 2]>>::try_from_iter((1..=3).hide_size()).expect_err("should overflow"), Some(2));
+
+test_position!(
+    validated,
+    (1..=5).try_collect_validated::<Vec<_>, _, _>(|&v| if v < 4 { Ok(()) } else { Err("too big") }).expect_err("should fail"),
+    Some(3)
+);
+
+test_position!(
+    result_iter_error,
+    Result::<HashSet<i32>, _>::try_from_iter([Ok(1), Ok(2), Err("bad"), Ok(3)]).expect_err("should fail"),
+    Some(2)
+);
+
+#[test]
+fn result_collection_error() {
+    let err = Result::<HashSet<i32>, _>::try_from_iter([Ok::<_, ()>(1), Ok(2), Ok(1)])
+        .expect("should not fail iteration")
+        .expect_err("should collide");
+
+    assert_eq!(err.position, Some(2), "should report the position of the colliding item");
+}
+
+#[test]
+fn unzip_left() {
+    let err = [(1, 1), (2, 2), (1, 3)]
+        .into_iter()
+        .try_unzip::<HashSet<_>, HashSet<_>>()
+        .expect_err("should collide")
+        .left()
+        .expect("should collide on left side");
+
+    assert_eq!(err.position, Some(2), "should report the position of the colliding pair");
+}
+
+#[test]
+fn unzip_right() {
+    let err = [(1, 1), (2, 2), (3, 2)]
+        .into_iter()
+        .try_unzip::<HashSet<_>, HashSet<_>>()
+        .expect_err("should collide")
+        .right()
+        .expect("should collide on right side");
+
+    assert_eq!(err.position, Some(2), "should report the position of the colliding pair");
+}
+
+#[test]
+fn tuple_extend() {
+    let mut sets = (HashSet::new(), HashSet::new());
+    let err = sets.try_extend([(1, 1), (2, 1)]).expect_err("should collide").right().expect("should collide on right side");
+
+    assert_eq!(err.position, Some(1), "should report the position of the colliding pair");
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use collect_failable::iter::LimitPrealloc;
+use collect_failable::{TryCollectEx, TryExtend, TryExtendSafe};
+use indexmap::IndexMap;
+use size_hinter::SizeHinter;
+
+/// An iterator whose lower bound is far larger than can be allocated
+fn oversized() -> impl Iterator<Item = (i32, i32)> + Debug {
+    (1..=3).map(|i| (i, i)).hide_size().hint_min(usize::MAX / 2)
+}
+
+macro_rules! test_limit_prealloc {
+    ($name:ident, $type:ty) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn collect() {
+                let map: $type = LimitPrealloc::new(oversized(), 16).try_collect_ex().expect("should not collide");
+
+                assert_eq!(map.len(), 3);
+            }
+
+            #[test]
+            fn extend() {
+                let mut map = <$type>::default();
+
+                map.try_extend(LimitPrealloc::new(oversized(), 16)).expect("should not collide");
+
+                assert_eq!(map.len(), 3);
+            }
+
+            #[test]
+            fn extend_safe() {
+                let mut map = <$type>::default();
+
+                map.try_extend_safe(LimitPrealloc::new(oversized(), 16)).expect("should not collide");
+
+                assert_eq!(map.len(), 3);
+            }
+
+            #[test]
+            fn try_reserve_into_fails() {
+                let mut map = <$type>::default();
+
+                let err = oversized().try_reserve_into(&mut map).expect_err("should fail to allocate");
+
+                assert_eq!(err.into_data().remain.count(), 3, "no items should be lost");
+                assert!(map.is_empty());
+            }
+
+            #[test]
+            fn try_reserve_into_reserves() {
+                let mut map = <$type>::default();
+
+                let iter = (1..=3).map(|i| (i, i)).try_reserve_into(&mut map).expect("should allocate");
+
+                assert!(map.capacity() >= 3);
+                map.try_extend(iter).expect("should not collide");
+            }
+        }
+    };
+}
+
+test_limit_prealloc!(hash_map, HashMap<i32, i32>);
+test_limit_prealloc!(hashbrown_map, hashbrown::HashMap<i32, i32>);
+test_limit_prealloc!(index_map, IndexMap<i32, i32>);
+
+#[test]
+fn try_reserve_into_vec() {
+    let mut vec = Vec::<i32>::new();
+
+    let err = (1..=3).hide_size().hint_min(usize::MAX).try_reserve_into(&mut vec).expect_err("should fail to allocate");
+
+    assert_eq!(err.into_data().remain.count(), 3);
+}
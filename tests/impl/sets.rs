@@ -35,3 +35,5 @@ test_sets_preserve_original_value!(hash_set, HashSet<_>);
 test_sets_preserve_original_value!(btree_set, BTreeSet<_>);
 test_sets_preserve_original_value!(hashbrown_set, HashBrownSet<_>);
 test_sets_preserve_original_value!(index_set, indexmap::IndexSet<_>);
+test_sets_preserve_original_value!(linked_hash_set, hashlink::LinkedHashSet<_>);
+test_sets_preserve_original_value!(order_set, ordermap::OrderSet<_>);
@@ -0,0 +1,115 @@
+use arrayvec::ArrayVec;
+use collect_failable::TryCollectEx;
+use collect_failable::errors::capacity::{CapacityError, CapacityErrorKind};
+use collect_failable::errors::partial_array::PartialArray;
+use collect_failable::errors::size_hint::{SizeHintViolation, SizeHintViolationKind};
+use collect_failable::iter::CheckedSizeHint;
+use size_hinter::{InvalidIterator, SizeHint, SizeHinter};
+
+mod checked {
+    use super::*;
+
+    #[test]
+    fn honest_iterator_has_no_violation() {
+        let mut iter = CheckedSizeHint::new(1..=3);
+
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(iter.produced(), 3);
+        assert_eq!(iter.check().expect("should not be violated").count(), 0);
+    }
+
+    #[test]
+    fn invalid_hint() {
+        let iter = CheckedSizeHint::new(InvalidIterator::<i32>::new());
+
+        assert_eq!(iter.size_hint(), (0, None), "hint should be valid");
+        assert_eq!(iter.violation(), Some(SizeHintViolation::validate((10, Some(5))).unwrap_err()));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut iter = CheckedSizeHint::new((1..=4).hide_size().hint_size(0, 2));
+
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 2, 3, 4], "all items should be produced");
+        assert_eq!(iter.violation(), Some(SizeHintViolation::overflow((0, Some(2)), 3)));
+        assert_eq!(iter.size_hint(), (0, None));
+    }
+
+    #[test]
+    fn underflow() {
+        let mut iter = CheckedSizeHint::new((1..=2).hide_size().hint_size(3, 4));
+
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(iter.violation(), Some(SizeHintViolation::underflow((3, Some(4)), 2)));
+    }
+}
+
+mod strict {
+    use super::*;
+
+    fn kind_of(violation: SizeHintViolationKind) -> impl Fn(&CapacityErrorKind<i32>) -> bool {
+        move |kind| matches!(kind, CapacityErrorKind::SizeHint { violation: v } if v.kind == violation)
+    }
+
+    #[test]
+    fn collect_valid() {
+        let array = (1..=3).try_collect_strict::<[i32; 3], _>().expect("should collect");
+
+        assert_eq!(array, [1, 2, 3]);
+    }
+
+    #[test]
+    fn collect_invalid_hint() {
+        let err = InvalidIterator::<i32>::new().try_collect_strict::<[i32; 3], _>().expect_err("should be invalid");
+
+        assert!(kind_of(SizeHintViolationKind::Invalid)(&err.error.kind));
+        assert_eq!(err.error.capacity, SizeHint::exact(3));
+    }
+
+    #[test]
+    fn collect_underflow() {
+        let err = (1..=2).hide_size().hint_size(3, 3).try_collect_strict::<[i32; 3], _>().expect_err("should underflow");
+
+        assert!(kind_of(SizeHintViolationKind::Underflow { count: 2 })(&err.error.kind));
+        assert_eq!(err.into_iter().collect::<Vec<_>>(), [1, 2], "no items should be lost");
+    }
+
+    #[test]
+    fn collect_overflow_keeps_item() {
+        let err = (1..=3).hide_size().hint_size(2, 2).try_collect_strict::<ArrayVec<i32, 2>, _>().expect_err("should overflow");
+
+        assert_eq!(err.error, CapacityError::overflow(SizeHint::at_most(2), 3));
+        assert_eq!(err.into_iter().collect::<Vec<_>>(), [3, 1, 2], "no items should be lost");
+    }
+
+    #[test]
+    fn extend_invalid_hint() {
+        let mut array_vec = ArrayVec::<i32, 4>::from_iter([1]);
+
+        let err = InvalidIterator::<i32>::new().try_collect_into_strict(&mut array_vec).expect_err("should be invalid");
+
+        assert!(kind_of(SizeHintViolationKind::Invalid)(&err.error.kind));
+        assert_eq!(err.error.capacity, SizeHint::at_most(3));
+        assert_eq!(*array_vec, [1], "collection should be unchanged");
+    }
+
+    #[test]
+    fn extend_valid() {
+        let mut partial = PartialArray::<i32, 3>::new();
+
+        (1..=2).try_collect_into_strict(&mut partial).expect("should fit");
+
+        assert_eq!(partial.len(), 2);
+    }
+
+    #[test]
+    fn extend_safe_invalid_hint() {
+        let mut partial = PartialArray::<i32, 3>::new();
+
+        let err = InvalidIterator::<i32>::new().try_collect_into_safe_strict(&mut partial).expect_err("should be invalid");
+
+        assert!(kind_of(SizeHintViolationKind::Invalid)(&err.error.kind));
+        assert!(partial.is_empty(), "collection should be unchanged");
+    }
+}
@@ -0,0 +1,106 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::collection_tests::{recover_iter_data, try_collect};
+use crate::utils::panics;
+
+use collect_failable::errors::capacity::{CapacityError, FixedCap, RemainingCap};
+use collect_failable::{TryCollectEx, TryFromIterator, TryFromIteratorWithCap};
+
+use size_hinter::{InvalidIterator, SizeHint, SizeHinter};
+
+type BoxedArray = Box<[u32; 5]>;
+
+mod boxed_array {
+    use super::*;
+
+    try_collect!(valid, BoxedArray, 1..=5, Ok(Box::new([1, 2, 3, 4, 5])));
+    try_collect!(too_long, BoxedArray, 1..=6, Err(CapacityError::bounds(SizeHint::exact(5), SizeHint::exact(6))));
+    try_collect!(too_short, BoxedArray, 1..=4, Err(CapacityError::bounds(SizeHint::exact(5), SizeHint::exact(4))));
+    try_collect!(too_long_hidden, BoxedArray, (1..=6).hide_size(), Err(CapacityError::overflow(SizeHint::exact(5), 6)));
+    try_collect!(too_short_hidden, BoxedArray, (1..=4).hide_size(), Err(CapacityError::underflow(SizeHint::exact(5), 4)));
+
+    panics!(invalid_iter, BoxedArray::try_from_iter(InvalidIterator::DEFAULT), "Invalid size hint");
+
+    recover_iter_data!(recover_underflow, BoxedArray, (1..4).hide_size(), vec![1, 2, 3], vec![1, 2, 3]);
+    recover_iter_data!(recover_overflow, BoxedArray, (1..=6).hide_size(), vec![1, 2, 3, 4, 5], vec![6, 1, 2, 3, 4, 5]);
+
+    #[test]
+    fn capacity() {
+        assert_eq!(BoxedArray::CAP, SizeHint::exact(5));
+        assert_eq!(Box::new([0; 5]).remaining_cap(), SizeHint::ZERO);
+    }
+
+    #[test]
+    fn try_collect_ex() {
+        let array: BoxedArray = (1..=5).try_collect_ex().expect("should succeed");
+        assert_eq!(*array, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn allocates_size_up_front() {
+        let err = BoxedArray::try_from_iter((1..=4).hide_size()).expect_err("should underflow");
+        assert_eq!(err.into_data().collected.capacity(), 5, "should allocate N despite the hidden size");
+    }
+}
+
+/// Generates tests for a [`TryFromIteratorWithCap`] implementation.
+macro_rules! with_cap_tests {
+    ($module:ident, $type:ty) => {
+        mod $module {
+            use super::*;
+
+            #[test]
+            fn exact() {
+                let slice = <$type>::try_from_iter_with_cap(1..=3, SizeHint::exact(3)).expect("should fit");
+                assert_eq!(*slice, [1, 2, 3]);
+            }
+
+            #[test]
+            fn bounded() {
+                let slice = <$type>::try_from_iter_with_cap((1..=3).hide_size(), SizeHint::bounded(2, 4)).expect("should fit");
+                assert_eq!(*slice, [1, 2, 3]);
+            }
+
+            #[test]
+            fn unbounded() {
+                let slice = <$type>::try_from_iter_with_cap((1..=3).hide_size(), SizeHint::unbounded(1)).expect("should fit");
+                assert_eq!(*slice, [1, 2, 3]);
+            }
+
+            #[test]
+            fn bounds() {
+                let err = <$type>::try_from_iter_with_cap(1..=3, SizeHint::at_most(2)).expect_err("should not fit");
+                assert_eq!(err.error, CapacityError::bounds(SizeHint::at_most(2), SizeHint::exact(3)));
+                assert_eq!(err.into_iter().collect::<Vec<_>>(), [1, 2, 3], "no items should be lost");
+            }
+
+            #[test]
+            fn overflow() {
+                let err = <$type>::try_from_iter_with_cap((1..=4).hide_size(), SizeHint::at_most(2)).expect_err("should overflow");
+                assert_eq!(err.error, CapacityError::overflow(SizeHint::at_most(2), 3));
+                assert_eq!(err.collected, [1, 2]);
+                assert_eq!(err.into_iter().collect::<Vec<_>>(), [3, 1, 2, 4], "no items should be lost");
+            }
+
+            #[test]
+            fn underflow() {
+                let err = <$type>::try_from_iter_with_cap((1..=2).hide_size(), SizeHint::unbounded(3)).expect_err("should underflow");
+                assert_eq!(err.error, CapacityError::underflow(SizeHint::unbounded(3), 2));
+                assert_eq!(err.into_iter().collect::<Vec<_>>(), [1, 2], "no items should be lost");
+            }
+
+            #[test]
+            fn try_collect_ex_with_cap() {
+                let slice: $type = (1..=3).try_collect_ex_with_cap(SizeHint::exact(3)).expect("should fit");
+                assert_eq!(*slice, [1, 2, 3]);
+            }
+
+            panics!(invalid_iter, <$type>::try_from_iter_with_cap(InvalidIterator::DEFAULT, SizeHint::exact(1)), "Invalid size hint");
+        }
+    };
+}
+
+with_cap_tests!(boxed_slice, Box<[i32]>);
+with_cap_tests!(rc_slice, Rc<[i32]>);
+with_cap_tests!(arc_slice, Arc<[i32]>);
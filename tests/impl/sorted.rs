@@ -0,0 +1,116 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use collect_failable::errors::sorted::SortedError;
+use collect_failable::{TryExtendSorted, TryFromSortedIterator};
+
+/// Macro for try_from_sorted_iter tests
+///
+/// Supports:
+/// - `try_collect_sorted!(name, Type, iter, Ok(expected))`
+/// - `try_collect_sorted!(name, Type, iter, Err(expected_error, expected_collected, expected_data))`
+macro_rules! try_collect_sorted {
+    ($name:ident, $type:ty, $iter:expr, Ok($expected:expr)) => {
+        #[test]
+        fn $name() {
+            let found = <$type>::try_from_sorted_iter($iter).expect("should be ok");
+            assert_eq!(found, $expected, "should match expected value");
+        }
+    };
+
+    ($name:ident, $type:ty, $iter:expr, Err($expected_error:expr, $expected_collected:expr, $expected_data:expr)) => {
+        #[test]
+        fn $name() {
+            let err = <$type>::try_from_sorted_iter($iter).expect_err("should be err");
+            assert_eq!(err.error, $expected_error, "should match expected error");
+            assert_eq!(err.collected, $expected_collected, "should match expected collected");
+            let data: Vec<_> = err.into_iter().collect();
+            assert_eq!(data, $expected_data, "should recover all items");
+        }
+    };
+}
+
+/// Macro for try_extend_sorted tests
+///
+/// Supports:
+/// - `try_extend_sorted!(name, initial, iter, Ok(expected))`
+/// - `try_extend_sorted!(name, initial, iter, Err(expected_error, expected_data))`
+macro_rules! try_extend_sorted {
+    ($name:ident, $initial:expr, $iter:expr, Ok($expected:expr)) => {
+        #[test]
+        fn $name() {
+            let mut collection = $initial;
+            collection.try_extend_sorted($iter).expect("should extend successfully");
+            assert_eq!(collection, $expected, "should match expected value");
+        }
+    };
+
+    ($name:ident, $initial:expr, $iter:expr, Err($expected_error:expr, $expected_data:expr)) => {
+        #[test]
+        fn $name() {
+            let mut collection = $initial;
+            let err = collection.try_extend_sorted($iter).expect_err("should fail to extend");
+            assert_eq!(collection, $initial, "should be unchanged on error");
+            assert_eq!(err.error, $expected_error, "should match expected error");
+            let data: Vec<_> = err.into_iter().collect();
+            assert_eq!(data, $expected_data, "should recover all items");
+        }
+    };
+}
+
+mod btree_map {
+    use super::*;
+
+    type Map = BTreeMap<i32, i32>;
+
+    try_collect_sorted!(empty, Map, [], Ok(Map::new()));
+    try_collect_sorted!(valid, Map, [(1, 1), (2, 2), (3, 3)], Ok(Map::from([(1, 1), (2, 2), (3, 3)])));
+    try_collect_sorted!(
+        collision,
+        Map,
+        [(1, 1), (2, 2), (2, 3), (3, 3)],
+        Err(SortedError::collision((2, 3)), Map::from([(1, 1), (2, 2)]), vec![(2, 3), (1, 1), (2, 2), (3, 3)])
+    );
+    try_collect_sorted!(
+        not_sorted,
+        Map,
+        [(1, 1), (3, 3), (2, 2), (4, 4)],
+        Err(SortedError::not_sorted((2, 2)), Map::from([(1, 1), (3, 3)]), vec![(2, 2), (1, 1), (3, 3), (4, 4)])
+    );
+
+    try_extend_sorted!(extend_valid, Map::from([(1, 1), (4, 4)]), [(2, 2), (3, 3)], Ok(Map::from([(1, 1), (2, 2), (3, 3), (4, 4)])));
+    try_extend_sorted!(extend_empty, Map::from([(1, 1)]), [], Ok(Map::from([(1, 1)])));
+    try_extend_sorted!(
+        extend_collision_with_map,
+        Map::from([(1, 1), (4, 4)]),
+        [(2, 2), (4, 5), (5, 5)],
+        Err(SortedError::collision((4, 5)), vec![(4, 5), (2, 2), (5, 5)])
+    );
+    try_extend_sorted!(
+        extend_self_collision,
+        Map::from([(1, 1)]),
+        [(2, 2), (2, 3)],
+        Err(SortedError::collision((2, 3)), vec![(2, 3), (2, 2)])
+    );
+    try_extend_sorted!(
+        extend_not_sorted,
+        Map::from([(1, 1)]),
+        [(3, 3), (2, 2), (4, 4)],
+        Err(SortedError::not_sorted((2, 2)), vec![(2, 2), (3, 3), (4, 4)])
+    );
+}
+
+mod btree_set {
+    use super::*;
+
+    type Set = BTreeSet<i32>;
+
+    try_collect_sorted!(empty, Set, [], Ok(Set::new()));
+    try_collect_sorted!(valid, Set, 1..=3, Ok(Set::from([1, 2, 3])));
+    try_collect_sorted!(collision, Set, [1, 2, 2, 3], Err(SortedError::collision(2), Set::from([1, 2]), vec![2, 1, 2, 3]));
+    try_collect_sorted!(not_sorted, Set, [1, 3, 2, 4], Err(SortedError::not_sorted(2), Set::from([1, 3]), vec![2, 1, 3, 4]));
+
+    try_extend_sorted!(extend_valid, Set::from([1, 4]), [2, 3], Ok(Set::from([1, 2, 3, 4])));
+    try_extend_sorted!(extend_collision_with_set, Set::from([1, 4]), [2, 4, 5], Err(SortedError::collision(4), vec![4, 2, 5]));
+    try_extend_sorted!(extend_self_collision, Set::from([1]), [2, 2], Err(SortedError::collision(2), vec![2, 2]));
+    try_extend_sorted!(extend_not_sorted, Set::from([1]), [3, 2, 4], Err(SortedError::not_sorted(2), vec![2, 3, 4]));
+}
@@ -0,0 +1,93 @@
+use crate::collection_tests::{recover_iter_data, try_collect, try_extend, try_extend_one, try_extend_safe};
+
+use collect_failable::collections::{SortedSet, SortedVec};
+use collect_failable::errors::sorted::SortedError;
+use collect_failable::{TryExtend, TryExtendOne, TryExtendSafe, TryFromIterator};
+
+fn sorted_vec<const N: usize>(items: [i32; N]) -> SortedVec<i32> {
+    SortedVec::try_from_iter(items).expect("should be sorted")
+}
+
+fn sorted_set<const N: usize>(items: [i32; N]) -> SortedSet<i32> {
+    SortedSet::try_from_iter(items).expect("should be sorted")
+}
+
+mod sorted_vec {
+    use super::*;
+
+    try_collect!(empty, SortedVec<i32>, [], Ok(SortedVec::new()));
+    try_collect!(valid, SortedVec<i32>, [1, 2, 2, 3], Ok(sorted_vec([1, 2, 2, 3])));
+    try_collect!(not_sorted, SortedVec<i32>, [1, 3, 2], Err(SortedError::not_sorted(2)));
+
+    recover_iter_data!(recover_not_sorted, SortedVec<i32>, [1, 3, 2, 4], sorted_vec([1, 3]), vec![2, 1, 3, 4]);
+
+    try_extend!(extend_valid, sorted_vec([1, 2]), [2, 3], Ok(sorted_vec([1, 2, 2, 3])));
+    try_extend!(extend_before_last, sorted_vec([1, 3]), [2, 4], Err(SortedError::not_sorted(2), [4].into_iter()));
+
+    try_extend_safe!(extend_safe_valid, sorted_vec([1, 2]), [2, 3], Ok(sorted_vec([1, 2, 2, 3])));
+    try_extend_safe!(
+        extend_safe_before_last,
+        sorted_vec([1, 3]),
+        [2, 4],
+        Err(SortedError::not_sorted(2), SortedVec::new(), [4].into_iter())
+    );
+    try_extend_safe!(
+        extend_safe_not_sorted,
+        sorted_vec([1]),
+        [2, 4, 3, 5],
+        Err(SortedError::not_sorted(3), sorted_vec([2, 4]), [5].into_iter())
+    );
+
+    try_extend_one!(extend_one_valid, sorted_vec([1, 2]), 2, Ok(sorted_vec([1, 2, 2])));
+    try_extend_one!(extend_one_empty, SortedVec::new(), 1, Ok(sorted_vec([1])));
+    try_extend_one!(extend_one_not_sorted, sorted_vec([1, 3]), 2, Err(SortedError::not_sorted(2)));
+
+    #[test]
+    fn accessors() {
+        let mut sorted = sorted_vec([1, 2, 3]);
+        assert_eq!(sorted.as_ref(), [1, 2, 3]);
+        assert_eq!((&sorted).into_iter().collect::<Vec<_>>(), [&1, &2, &3]);
+        assert_eq!(sorted.pop(), Some(3));
+        assert_eq!(Vec::from(sorted.clone()), [1, 2]);
+        sorted.clear();
+        assert!(sorted.is_empty());
+        assert_eq!(sorted, SortedVec::default());
+    }
+}
+
+mod sorted_set {
+    use super::*;
+
+    try_collect!(empty, SortedSet<i32>, [], Ok(SortedSet::new()));
+    try_collect!(valid, SortedSet<i32>, [1, 2, 3], Ok(sorted_set([1, 2, 3])));
+    try_collect!(collision, SortedSet<i32>, [1, 2, 2], Err(SortedError::collision(2)));
+    try_collect!(not_sorted, SortedSet<i32>, [1, 3, 2], Err(SortedError::not_sorted(2)));
+
+    recover_iter_data!(recover_collision, SortedSet<i32>, [1, 2, 2, 3], sorted_set([1, 2]), vec![2, 1, 2, 3]);
+
+    try_extend!(extend_valid, sorted_set([1, 2]), [3, 4], Ok(sorted_set([1, 2, 3, 4])));
+    try_extend!(extend_collide_last, sorted_set([1, 2]), [2, 3], Err(SortedError::collision(2), [3].into_iter()));
+
+    try_extend_safe!(extend_safe_valid, sorted_set([1, 2]), [3, 4], Ok(sorted_set([1, 2, 3, 4])));
+    try_extend_safe!(
+        extend_safe_collide_last,
+        sorted_set([1, 2]),
+        [2, 3],
+        Err(SortedError::collision(2), SortedSet::new(), [3].into_iter())
+    );
+    try_extend_safe!(
+        extend_safe_self_collision,
+        sorted_set([1]),
+        [2, 3, 3, 4],
+        Err(SortedError::collision(3), sorted_set([2, 3]), [4].into_iter())
+    );
+
+    try_extend_one!(extend_one_valid, sorted_set([1, 2]), 3, Ok(sorted_set([1, 2, 3])));
+    try_extend_one!(extend_one_collision, sorted_set([1, 2]), 2, Err(SortedError::collision(2)));
+    try_extend_one!(extend_one_not_sorted, sorted_set([1, 3]), 2, Err(SortedError::not_sorted(2)));
+
+    #[test]
+    fn into_vec() {
+        assert_eq!(sorted_set([1, 2, 3]).into_vec(), [1, 2, 3]);
+    }
+}
@@ -0,0 +1,223 @@
+use std::cell::Cell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use arrayvec::ArrayVec;
+use collect_failable::errors::Collision;
+use collect_failable::{TryCollectEx, TryExtendCloned, TryExtendClonedSafe};
+
+thread_local! {
+    static CLONES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A key that counts how many times it has been cloned
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Counted(i32);
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.set(CLONES.get() + 1);
+        Self(self.0)
+    }
+}
+
+fn clones() -> usize {
+    CLONES.replace(0)
+}
+
+macro_rules! test_try_extend_cloned_set {
+    ($name:ident, $type:ty) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn valid() {
+                let items = [Counted(2), Counted(3)];
+                let mut set = <$type>::from_iter([Counted(1)]);
+                clones();
+
+                set.try_extend_cloned(&items).expect("should not collide");
+
+                assert_eq!(clones(), 2);
+                assert_eq!(set, <$type>::from_iter([Counted(1), Counted(2), Counted(3)]));
+            }
+
+            #[test]
+            fn collision_does_not_clone_rejected() {
+                let items = [Counted(2), Counted(1), Counted(3)];
+                let mut set = <$type>::from_iter([Counted(1)]);
+                clones();
+
+                let err = set.try_extend_cloned(&items).expect_err("should collide");
+
+                assert_eq!(clones(), 1, "only the accepted item should be cloned");
+                assert_eq!(err.error, Collision::new(&items[1]));
+                assert_eq!(err.position, Some(1));
+                assert_eq!(err.into_data().remain.collect::<Vec<_>>(), [&items[2]]);
+                assert_eq!(set, <$type>::from_iter([Counted(1), Counted(2)]));
+            }
+
+            #[test]
+            fn safe_collision_restores() {
+                let items = [Counted(2), Counted(3), Counted(1), Counted(4)];
+                let mut set = <$type>::from_iter([Counted(1)]);
+                clones();
+
+                let err = set.try_extend_cloned_safe(&items).expect_err("should collide");
+
+                assert_eq!(clones(), 2, "only the accepted items should be cloned");
+                assert_eq!(err.error, Collision::new(&items[2]));
+                assert_eq!(err.position, Some(2));
+                assert_eq!(err.collected, [&items[0], &items[1]]);
+                assert_eq!(set, <$type>::from_iter([Counted(1)]), "set should be unchanged");
+            }
+
+            #[test]
+            fn collect_cloned() {
+                let items = [Counted(1), Counted(2), Counted(1)];
+                clones();
+
+                let err = items.iter().try_collect_cloned::<$type, _>().expect_err("should collide");
+
+                assert_eq!(clones(), 2, "only the accepted items should be cloned");
+                assert_eq!(err.error, Collision::new(&items[2]));
+                assert_eq!(err.position, Some(2));
+                assert_eq!(err.into_data().collected, <$type>::from_iter([Counted(1), Counted(2)]));
+            }
+        }
+    };
+}
+
+macro_rules! test_try_extend_cloned_map {
+    ($name:ident, $type:ty) => {
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn collision_does_not_clone_rejected() {
+                let source = BTreeMap::from([(Counted(1), 'b'), (Counted(2), 'c'), (Counted(3), 'd')]);
+                let mut map = <$type>::from_iter([(Counted(2), 'a')]);
+                clones();
+
+                let err = map.try_extend_cloned(&source).expect_err("should collide");
+
+                assert_eq!(clones(), 1, "only the accepted key should be cloned");
+                assert_eq!(err.error, Collision::new((&Counted(2), &'c')));
+                assert_eq!(map, <$type>::from_iter([(Counted(2), 'a'), (Counted(1), 'b')]));
+            }
+
+            #[test]
+            fn safe_collision_restores() {
+                let source = BTreeMap::from([(Counted(1), 'b'), (Counted(2), 'c'), (Counted(3), 'd')]);
+                let mut map = <$type>::from_iter([(Counted(2), 'a')]);
+                clones();
+
+                let err = map.try_extend_cloned_safe(&source).expect_err("should collide");
+
+                assert_eq!(clones(), 1, "only the accepted key should be cloned");
+                assert_eq!(err.error, Collision::new((&Counted(2), &'c')));
+                assert_eq!(err.collected, [(&Counted(1), &'b')]);
+                assert_eq!(map, <$type>::from_iter([(Counted(2), 'a')]), "map should be unchanged");
+            }
+        }
+    };
+}
+
+test_try_extend_cloned_set!(hash_set, HashSet<Counted>);
+test_try_extend_cloned_set!(btree_set, BTreeSet<Counted>);
+test_try_extend_cloned_set!(hashbrown_set, hashbrown::HashSet<Counted>);
+test_try_extend_cloned_set!(index_set, indexmap::IndexSet<Counted>);
+test_try_extend_cloned_set!(linked_hash_set, hashlink::LinkedHashSet<Counted>);
+test_try_extend_cloned_set!(order_set, ordermap::OrderSet<Counted>);
+
+test_try_extend_cloned_map!(hash_map, HashMap<Counted, char>);
+test_try_extend_cloned_map!(btree_map, BTreeMap<Counted, char>);
+test_try_extend_cloned_map!(hashbrown_map, hashbrown::HashMap<Counted, char>);
+test_try_extend_cloned_map!(index_map, indexmap::IndexMap<Counted, char>);
+test_try_extend_cloned_map!(linked_hash_map, hashlink::LinkedHashMap<Counted, char>);
+test_try_extend_cloned_map!(order_map, ordermap::OrderMap<Counted, char>);
+
+#[test]
+fn index_set_safe_collision_keeps_order() {
+    let items = [Counted(4), Counted(5), Counted(2)];
+    let mut set = indexmap::IndexSet::from([Counted(3), Counted(2), Counted(1)]);
+
+    set.try_extend_cloned_safe(&items).expect_err("should collide");
+
+    assert!(set.iter().eq(&[Counted(3), Counted(2), Counted(1)]), "order should be unchanged");
+}
+
+#[test]
+fn array_vec_overflow_does_not_clone_rejected() {
+    let items = [Counted(1), Counted(2), Counted(3)];
+    let mut array = ArrayVec::<Counted, 3>::from_iter([Counted(0)]);
+    clones();
+
+    let err = array.try_extend_cloned(items.iter().filter(|_| true)).expect_err("should overflow");
+
+    assert_eq!(clones(), 2, "only the accepted items should be cloned");
+    assert_eq!(err.position, Some(2));
+    assert_eq!(err.into_iter().collect::<Vec<_>>(), [&items[2]]);
+    assert_eq!(*array, [Counted(0), Counted(1), Counted(2)]);
+}
+
+#[test]
+fn array_vec_bounds_does_not_clone() {
+    let items = [Counted(1), Counted(2), Counted(3)];
+    let mut array = ArrayVec::<Counted, 3>::from_iter([Counted(0)]);
+    clones();
+
+    let err = array.try_extend_cloned(&items).expect_err("should not fit");
+
+    assert_eq!(clones(), 0);
+    assert_eq!(err.into_iter().count(), 3, "no items should be lost");
+    assert_eq!(*array, [Counted(0)]);
+}
+
+#[test]
+fn array_vec_safe_overflow_does_not_clone() {
+    let items = [Counted(1), Counted(2), Counted(3)];
+    let mut array = ArrayVec::<Counted, 3>::from_iter([Counted(0)]);
+    clones();
+
+    let err = array.try_extend_cloned_safe(items.iter().filter(|_| true)).expect_err("should overflow");
+
+    assert_eq!(clones(), 0, "no item should be cloned on failure");
+    assert_eq!(err.position, Some(2));
+    assert_eq!(*err.collected, [&items[0], &items[1]]);
+    assert_eq!(*array, [Counted(0)], "array should be unchanged");
+}
+
+#[test]
+fn array_vec_safe_valid() {
+    let items = [Counted(1), Counted(2)];
+    let mut array = ArrayVec::<Counted, 3>::from_iter([Counted(0)]);
+    clones();
+
+    array.try_extend_cloned_safe(&items).expect("should fit");
+
+    assert_eq!(clones(), 2);
+    assert_eq!(*array, [Counted(0), Counted(1), Counted(2)]);
+}
+
+#[test]
+fn array_vec_collect_cloned() {
+    let items = [Counted(1), Counted(2), Counted(3)];
+
+    let err = items.iter().filter(|_| true).try_collect_cloned::<ArrayVec<Counted, 2>, _>().expect_err("should overflow");
+
+    assert_eq!(*err.collected, [Counted(1), Counted(2)]);
+    assert_eq!(err.into_data().remain.count(), 0);
+}
+
+#[test]
+fn vec_clones_every_item() {
+    let items = [Counted(1), Counted(2)];
+    let mut vec = vec![Counted(0)];
+    clones();
+
+    vec.try_extend_cloned(&items).expect("should not fail");
+    vec.try_extend_cloned_safe(&items).expect("should not fail");
+
+    assert_eq!(clones(), 4);
+    assert_eq!(vec, [Counted(0), Counted(1), Counted(2), Counted(1), Counted(2)]);
+}
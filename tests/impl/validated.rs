@@ -0,0 +1,112 @@
+use crate::collection_tests::{recover_iter_data, try_collect, try_extend, try_extend_one, try_extend_safe};
+
+use std::collections::HashSet;
+
+use collect_failable::collections::{Validated, Validator, from_fn};
+use collect_failable::errors::invalid::Invalid;
+use collect_failable::errors::{Collision, WrapperError};
+use collect_failable::{TryExtend, TryExtendOne, TryExtendSafe, TryFromIterator};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Positive;
+
+impl Validator for Positive {
+    type Item = i32;
+    type Error = &'static str;
+
+    fn validate(&self, item: &i32) -> Result<(), Self::Error> {
+        match *item > 0 {
+            true => Ok(()),
+            false => Err("not positive"),
+        }
+    }
+}
+
+type Positives = Validated<Vec<i32>, Positive>;
+
+fn positives<const N: usize>(items: [i32; N]) -> Positives {
+    Positives::try_from_iter(items).expect("should be valid")
+}
+
+const fn invalid<Inner>(item: i32) -> WrapperError<Invalid<i32, &'static str>, Inner> {
+    WrapperError::Wrapper(Invalid { item, error: "not positive" })
+}
+
+try_collect!(empty, Positives, [], Ok(Positives::default()));
+try_collect!(valid, Positives, [1, 2, 3], Ok(positives([1, 2, 3])));
+try_collect!(invalid_item, Positives, [1, -2, 3], Err(invalid(-2)));
+
+recover_iter_data!(recover_invalid, Positives, [1, -2, 3], positives([1]), vec![-2, 1, 3]);
+
+try_extend!(extend_valid, positives([1]), [2, 3], Ok(positives([1, 2, 3])));
+try_extend!(extend_invalid, positives([1]), [2, 0, 3], Err(invalid(0), [3].into_iter()));
+
+try_extend_safe!(extend_safe_valid, positives([1]), [2, 3], Ok(positives([1, 2, 3])));
+
+#[test]
+fn extend_safe_invalid() {
+    let mut items = positives([1]);
+
+    let err = items.try_extend_safe([2, 0, 3]).expect_err("should be invalid").into_wrapper().into_data();
+
+    assert_eq!(err.error, Invalid::new(0, "not positive"));
+    assert_eq!(err.collected, [2]);
+    assert!(err.remain.eq([3]));
+    assert_eq!(items, positives([1]));
+}
+
+try_extend_one!(extend_one_valid, positives([1]), 2, Ok(positives([1, 2])));
+try_extend_one!(extend_one_invalid, positives([1]), -1, Err(invalid(-1)));
+
+#[test]
+fn fn_pointer_validator() {
+    fn even(item: &i32) -> Result<(), i32> {
+        match item % 2 {
+            0 => Ok(()),
+            rem => Err(rem),
+        }
+    }
+
+    let mut evens = Validated::<Vec<i32>, fn(&i32) -> Result<(), i32>>::new(even);
+    evens.try_extend([2, 4]).expect("should be even");
+
+    let err = evens.try_extend_one(5).expect_err("should be odd");
+    assert_eq!(err, WrapperError::Wrapper(Invalid::new(5, 1)));
+    assert_eq!(evens.into_inner(), [2, 4]);
+}
+
+type PositiveSet = Validated<HashSet<i32>, Positive>;
+
+try_collect!(inner_collision, PositiveSet, [1, 2, 1], Err(WrapperError::Inner(Collision::new(1))));
+
+#[test]
+fn extend_inner_collision() {
+    let mut items = PositiveSet::try_from_iter([1]).expect("should be valid");
+
+    let err = items.try_extend([2, 1, 3]).expect_err("should collide");
+
+    assert_eq!(err.error, WrapperError::Inner(Collision::new(1)));
+    assert_eq!(*items, HashSet::from([1, 2]));
+}
+
+#[test]
+fn extend_safe_inner_collision() {
+    let mut items = PositiveSet::try_from_iter([1]).expect("should be valid");
+
+    let err = items.try_extend_safe([2, 1]).expect_err("should collide");
+
+    assert!(matches!(err, WrapperError::Inner(ref err) if err.error == Collision::new(1)));
+    assert_eq!(*items, HashSet::from([1]));
+}
+
+#[test]
+fn closure_validator() {
+    let max = 3;
+    let mut items = Validated::<Vec<i32>, _>::new(from_fn(|item: &i32| if *item <= max { Ok(()) } else { Err(*item) }));
+
+    items.try_extend([1, 3]).expect("should be small enough");
+
+    let err = items.try_extend_one(4).expect_err("should be too large");
+    assert_eq!(err, WrapperError::Wrapper(Invalid::new(4, 4)));
+    assert_eq!(items.into_inner(), [1, 3]);
+}
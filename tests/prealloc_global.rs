@@ -0,0 +1,15 @@
+use std::collections::HashSet;
+
+use collect_failable::{TryCollectEx, prealloc};
+use size_hinter::SizeHinter;
+
+/// `prealloc::set_max` affects the whole process, so this test lives in its own binary, where it
+/// can not race with tests that rely on the default limit.
+#[test]
+fn global_max_limits_prealloc() {
+    prealloc::set_max(16);
+    let result = (1..=3).hide_size().hint_min(usize::MAX / 2).try_collect_ex::<HashSet<_>>();
+    prealloc::set_max(usize::MAX);
+
+    assert_eq!(result.expect("should not collide"), HashSet::from([1, 2, 3]));
+}
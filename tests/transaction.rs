@@ -0,0 +1,58 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use collect_failable::{Transaction, TryExtend, TryExtendOne};
+
+#[test]
+fn commit_applies_changes() {
+    let mut set = HashSet::from([1]);
+
+    let mut tx = Transaction::begin(&mut set);
+    tx.try_extend([2, 3]).expect("should not collide");
+    tx.try_extend_one(4).expect("should not collide");
+    tx.commit();
+
+    assert_eq!(set, HashSet::from([1, 2, 3, 4]));
+}
+
+#[test]
+fn drop_rolls_back() {
+    let mut set = HashSet::from([1]);
+
+    let mut tx = Transaction::begin(&mut set);
+    tx.try_extend([2, 3]).expect("should not collide");
+    drop(tx);
+
+    assert_eq!(set, HashSet::from([1]), "set should be unchanged");
+}
+
+#[test]
+fn error_after_success_rolls_back_both() {
+    let mut primary = HashMap::from([(1, "a")]);
+    let mut index = BTreeMap::from([("a", 1)]);
+
+    let result = (|| {
+        let mut tx = Transaction::begin((&mut primary, &mut index));
+        tx.0.try_extend([(2, "b")])?;
+        tx.1.try_extend_one(("b", 2))?;
+        tx.0.try_extend_one((1, "c"))?;
+        tx.commit();
+        Ok::<_, Box<dyn std::error::Error>>(())
+    })();
+
+    result.expect_err("should collide");
+    assert_eq!(primary, HashMap::from([(1, "a")]), "primary should be unchanged");
+    assert_eq!(index, BTreeMap::from([("a", 1)]), "index should be unchanged");
+}
+
+#[test]
+fn triple_commit() {
+    let (mut a, mut b, mut c) = (vec![1], HashSet::from([1]), BTreeMap::from([(1, 1)]));
+
+    let mut tx = Transaction::begin((&mut a, &mut b, &mut c));
+    tx.0.push(2);
+    tx.1.try_extend_one(2).expect("should not collide");
+    tx.2.try_extend_one((2, 2)).expect("should not collide");
+    tx.commit();
+
+    assert_eq!((a, b, c), (vec![1, 2], HashSet::from([1, 2]), BTreeMap::from([(1, 1), (2, 2)])));
+}
@@ -7,3 +7,98 @@ fn test_try_collect_ex() {
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), [1, 2, 3]);
 }
+
+#[test]
+fn test_try_collect_validated() {
+    use collect_failable::TryCollectEx;
+
+    let non_empty = |s: &&str| if s.is_empty() { Err("empty") } else { Ok(()) };
+
+    let valid: Vec<_> = ["a", "b"].into_iter().try_collect_validated(non_empty).expect("should be valid");
+    assert_eq!(valid, ["a", "b"]);
+
+    let err = ["a", "", "c"].into_iter().try_collect_validated::<Vec<_>, _, _>(non_empty).expect_err("should be invalid");
+    assert_eq!(err.error.item, "");
+    assert_eq!(err.error.error, "empty");
+    assert_eq!(err.collected, ["a"]);
+    assert_eq!(err.into_iter().collect::<Vec<_>>(), ["", "a", "c"]);
+}
+
+#[test]
+fn test_try_collect_into() {
+    use collect_failable::TryCollectEx;
+    use std::collections::HashMap;
+
+    let mut map = HashMap::from([(1, 1)]);
+    let extended = [(2, 2)].into_iter().try_collect_into(&mut map).expect("should not collide");
+    assert_eq!(extended, &HashMap::from([(1, 1), (2, 2)]));
+
+    let err = [(3, 3), (1, 2), (4, 4)].into_iter().try_collect_into(&mut map).expect_err("should collide");
+    assert_eq!(err.error.item, (1, 2));
+    assert_eq!(map, HashMap::from([(1, 1), (2, 2), (3, 3)]), "basic guarantee may modify the map");
+}
+
+#[test]
+fn test_try_collect_skipping() {
+    use collect_failable::TryCollectEx;
+    use std::collections::BTreeSet;
+
+    let (skipped, result) = [1, 2, 1, 3, 2].into_iter().try_collect_skipping::<BTreeSet<_>, _>();
+    assert_eq!(result.expect("should skip every collision"), BTreeSet::from([1, 2, 3]));
+    assert_eq!(skipped.into_iter().map(|(position, err)| (position, err.item)).collect::<Vec<_>>(), [(2, 1), (4, 2)]);
+
+    let (skipped, result) = [1, 2].into_iter().try_collect_skipping::<BTreeSet<_>, _>();
+    assert_eq!(result.expect("should not collide"), BTreeSet::from([1, 2]));
+    assert!(skipped.is_empty());
+}
+
+#[test]
+fn test_try_collect_skipping_stops_without_item() {
+    use arrayvec::ArrayVec;
+    use collect_failable::TryCollectEx;
+
+    let (skipped, result) = [1, 2, 3].into_iter().try_collect_skipping::<ArrayVec<_, 2>, _>();
+    assert!(skipped.is_empty());
+
+    let err = result.expect_err("bounds error should not be skipped");
+    assert!(err.collected.is_empty(), "bounds error should not consume any items");
+    assert_eq!(err.into_data().remain.collect::<Vec<_>>(), [1, 2, 3], "remaining items should be returned");
+}
+
+#[test]
+fn test_collect_partitioned() {
+    use collect_failable::TryCollectEx;
+    use std::collections::HashSet;
+
+    let (set, rejects) = [1, 2, 1, 3, 2].into_iter().collect_partitioned::<HashSet<_>>();
+    assert_eq!(set, HashSet::from([1, 2, 3]));
+    assert_eq!(rejects.into_iter().map(|(position, err)| (position, err.item)).collect::<Vec<_>>(), [(2, 1), (4, 2)]);
+}
+
+#[test]
+fn test_collect_partitioned_overflow() {
+    use arrayvec::ArrayVec;
+    use collect_failable::TryCollectEx;
+    use collect_failable::errors::ErrorItemProvider;
+
+    let (array, rejects) = (1..=4).collect_partitioned::<ArrayVec<_, 2>>();
+    assert_eq!(array.as_slice(), [1, 2]);
+    assert_eq!(
+        rejects.into_iter().map(|(position, err)| (position, err.into_item())).collect::<Vec<_>>(),
+        [(2, Some(3)), (3, Some(4))]
+    );
+}
+
+#[test]
+fn test_try_collect_into_safe() {
+    use arrayvec::ArrayVec;
+    use collect_failable::TryCollectEx;
+
+    let mut array = ArrayVec::<_, 4>::from_iter([1, 2]);
+    let extended = [3].into_iter().try_collect_into_safe(&mut array).expect("should fit");
+    assert_eq!(extended.as_slice(), [1, 2, 3]);
+
+    let err = [4, 5].into_iter().try_collect_into_safe(&mut array).expect_err("should not fit");
+    assert_eq!(err.into_iter().collect::<Vec<_>>(), [4, 5]);
+    assert_eq!(array.as_slice(), [1, 2, 3], "strong guarantee should leave the array unchanged");
+}